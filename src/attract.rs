@@ -0,0 +1,212 @@
+// Arcade-style attract mode: the screensaver cycles through a title screen, a high
+// score screen and demo play (the AI game), fading between them like a cabinet
+// waiting for coins.
+
+use crate::game::GameState;
+use crate::hud::Hud;
+use crate::renderer::layout::{draw_text, format_number, line_width};
+use crate::renderer::{font, Align, Color, Frame, Layer, NumberFormat, TextStyle, Vertex};
+
+const FADE_TIME: f32 = 0.6; // Seconds to fade in at the start and out at the end of each screen
+pub const MIN_DEMO_TIME: f32 = 2.0 * FADE_TIME; // Room to fade in and out again
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AttractScreen {
+    Title,
+    HighScores,
+    Demo,
+}
+
+pub struct Attract {
+    pub enabled: bool, // When false the game plays forever, as before
+    pub screen: AttractScreen,
+    pub title_time: f32,       // Seconds on the title screen
+    pub high_scores_time: f32, // Seconds on the high score screen
+    pub demo_time: f32,        // Seconds of demo play before returning to the title
+    time_on_screen: f32,
+}
+
+impl Default for Attract {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            screen: AttractScreen::Title,
+            title_time: 6.0,
+            high_scores_time: 6.0,
+            demo_time: 90.0,
+            time_on_screen: 0.0,
+        }
+    }
+}
+
+impl Attract {
+    // Advance the sequence, running the game while it is on screen
+    pub fn update(&mut self, game_state: &mut GameState, delta_time: f32) {
+        if !self.enabled {
+            game_state.update(delta_time);
+            return;
+        }
+
+        // A player at the keyboard skips straight to the game and keeps it there
+        if game_state.is_player_controlled() {
+            if self.screen != AttractScreen::Demo {
+                self.screen = AttractScreen::Demo;
+                self.time_on_screen = FADE_TIME;
+            }
+            self.time_on_screen = self.time_on_screen.min(self.demo_time - FADE_TIME);
+        }
+
+        if self.screen == AttractScreen::Demo {
+            game_state.update(delta_time);
+        }
+
+        self.time_on_screen += delta_time;
+        if self.time_on_screen >= self.screen_time() {
+            self.screen = match self.screen {
+                AttractScreen::Title => AttractScreen::HighScores,
+                AttractScreen::HighScores => AttractScreen::Demo,
+                AttractScreen::Demo => AttractScreen::Title,
+            };
+            self.time_on_screen = 0.0;
+        }
+    }
+
+    fn screen_time(&self) -> f32 {
+        match self.screen {
+            AttractScreen::Title => self.title_time,
+            AttractScreen::HighScores => self.high_scores_time,
+            AttractScreen::Demo => self.demo_time,
+        }
+    }
+
+    // Brightness from 0 to 1: fading in, holding, then fading out
+    pub fn fade(&self) -> f32 {
+        if !self.enabled {
+            return 1.0;
+        }
+        let fade_in = self.time_on_screen / FADE_TIME;
+        let fade_out = (self.screen_time() - self.time_on_screen) / FADE_TIME;
+        fade_in.min(fade_out).clamp(0.0, 1.0)
+    }
+
+    // Add the current screen to the frame, faded
+    pub fn draw_frame(&self, game_state: &GameState, hud: &Hud, frame: &mut Frame) {
+        let start = frame.segments.len();
+        let screen = if self.enabled {
+            self.screen
+        } else {
+            AttractScreen::Demo
+        };
+        match screen {
+            AttractScreen::Title => frame.push_vertices(Layer::Game, &title_vertices(game_state)),
+            AttractScreen::HighScores => {
+                frame.push_vertices(Layer::Game, &high_score_vertices(game_state))
+            }
+            AttractScreen::Demo => hud.draw_frame(game_state, frame),
+        }
+
+        let fade = self.fade();
+        for segment in &mut frame.segments[start..] {
+            segment.color.a *= fade;
+        }
+    }
+}
+
+fn title_vertices(game_state: &GameState) -> Vec<Vertex> {
+    let mut vertices = render_logo(0.0, 0.15, 0.2, game_state.game_color);
+
+    let best = game_state.lifetime_totals().best_score;
+    vertices.extend(centered_label_and_number(
+        "HIGH SCORE",
+        best,
+        "",
+        -0.35,
+        0.05,
+        game_state.hud_color,
+    ));
+    vertices
+}
+
+fn high_score_vertices(game_state: &GameState) -> Vec<Vertex> {
+    let lifetime = game_state.lifetime_totals();
+    let color = game_state.hud_color;
+
+    let mut vertices = Vec::new();
+    let title_size = 0.08;
+    let title = "HIGH SCORES";
+    let title_style = TextStyle {
+        align: Align::Center,
+        ..TextStyle::label(title_size, game_state.game_color)
+    };
+    vertices.extend(draw_text(title, 0.0, 0.55, &title_style));
+
+    let rows = [
+        ("BEST SCORE", lifetime.best_score, ""),
+        ("LAST SCORE", game_state.score as u64, ""),
+        ("LONGEST LIFE", lifetime.longest_life as u64, "SEC"),
+        (
+            "ACCURACY",
+            (lifetime.accuracy() * 100.0).round() as u64,
+            "%",
+        ),
+        (
+            "SAUCERS DESTROYED",
+            { lifetime.large_saucers_destroyed + lifetime.small_saucers_destroyed },
+            "",
+        ),
+        ("MINUTES PLAYED", (lifetime.runtime / 60.0) as u64, ""),
+    ];
+
+    for (i, (label, value, unit)) in rows.into_iter().enumerate() {
+        let y = 0.3 - i as f32 * 0.14;
+        vertices.extend(centered_label_and_number(
+            label, value, unit, y, 0.05, color,
+        ));
+    }
+
+    vertices
+}
+
+// Label on the left of centre, number and unit on the right
+fn centered_label_and_number(
+    label: &str,
+    value: u64,
+    unit: &str,
+    y: f32,
+    size: f32,
+    color: Color,
+) -> Vec<Vertex> {
+    let gap = size;
+    let label_style = TextStyle {
+        align: Align::Right,
+        ..TextStyle::label(size, color)
+    };
+    let mut vertices = draw_text(label, -gap, y, &label_style);
+    let number = format_number(value as f64, &NumberFormat::default());
+    let number_style = TextStyle::number(size, color);
+    vertices.extend(draw_text(&number, gap, y, &number_style));
+    // In the stroke font, so an S cannot be read as a 7-segment 5
+    let unit_x = gap + line_width(&number, &number_style) + size * 0.4;
+    vertices.extend(draw_text(unit, unit_x, y, &TextStyle::label(size, color)));
+    vertices
+}
+
+// The "ASTEROIDS" logo centred on (x, y), `height` tall. The text is drawn twice,
+// slightly offset, for the heavier look of the cabinet artwork.
+fn render_logo(x: f32, y: f32, height: f32, color: Color) -> Vec<Vertex> {
+    let style = TextStyle {
+        align: Align::Center,
+        ..TextStyle::label(height / 0.6, color) // Label cap height is 0.6 of its size
+    };
+    let baseline = y - height * 0.5;
+    let weight = height / font::GLYPH_HEIGHT * 0.08;
+
+    let mut vertices = draw_text("ASTEROIDS", x, baseline, &style);
+    vertices.extend(draw_text(
+        "ASTEROIDS",
+        x + weight,
+        baseline + weight,
+        &style,
+    ));
+    vertices
+}
@@ -0,0 +1,90 @@
+// Headless AI evaluation across many seeds:
+//
+//   ai_eval [--personality <name> | --profile <file>] [--seeds <count>] [--first-seed <n>] [--minutes <m>] [--difficulty <0-1>] [--episodes]
+
+use asteroids_screensaver::config;
+use asteroids_screensaver::eval::{self, EvalSettings};
+use asteroids_screensaver::game::AiPersonality;
+
+fn main() {
+    let mut settings = EvalSettings::default();
+    let mut seed_count: u64 = 32;
+    let mut first_seed: u64 = 1;
+    let mut show_episodes = false;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--personality" => {
+                let name = args.next().unwrap_or_default();
+                match AiPersonality::from_name(&name) {
+                    Some(personality) => settings.profile = personality.profile(),
+                    None => exit_with_usage(&format!("Unknown personality '{}'", name)),
+                }
+            }
+            "--profile" => {
+                let path = args.next().unwrap_or_default();
+                match config::load_ai_profile(path.as_ref()) {
+                    Ok(profile) => settings.profile = profile,
+                    Err(e) => exit_with_usage(&format!("Could not read {}: {}", path, e)),
+                }
+            }
+            "--seeds" => seed_count = parse_next(&mut args, "--seeds"),
+            "--first-seed" => first_seed = parse_next(&mut args, "--first-seed"),
+            "--minutes" => {
+                let minutes: f32 = parse_next(&mut args, "--minutes");
+                if !(minutes.is_finite() && minutes > 0.0) {
+                    exit_with_usage("--minutes expects a positive number");
+                }
+                settings.duration = minutes * 60.0;
+            }
+            "--difficulty" => {
+                let level: f32 = parse_next(&mut args, "--difficulty");
+                if !(0.0..=1.0).contains(&level) {
+                    exit_with_usage("--difficulty expects a level from 0.0 to 1.0");
+                }
+                settings.difficulty = level;
+            }
+            "--episodes" => show_episodes = true,
+            _ => exit_with_usage(&format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    let Some(last_seed) = first_seed.checked_add(seed_count) else {
+        exit_with_usage("--first-seed plus --seeds is past the largest seed");
+    };
+    let seeds: Vec<u64> = (first_seed..last_seed).collect();
+    let report = eval::evaluate(&seeds, &settings);
+
+    if show_episodes {
+        for e in &report.episodes {
+            println!(
+                "seed {:>6}: survival {:>7.1}s  deaths/min {:>5.2}  accuracy {:>5.3}  points/min {:>8.1}  death loops {}",
+                e.seed,
+                e.survival_time,
+                e.deaths_per_minute,
+                e.accuracy,
+                e.points_per_minute,
+                e.death_loop_triggers
+            );
+        }
+        println!();
+    }
+
+    print!("{}", report);
+}
+
+fn parse_next<T: std::str::FromStr>(args: &mut impl Iterator<Item = String>, flag: &str) -> T {
+    match args.next().and_then(|value| value.parse().ok()) {
+        Some(value) => value,
+        None => exit_with_usage(&format!("{} expects a number", flag)),
+    }
+}
+
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("{}", message);
+    eprintln!(
+        "Usage: ai_eval [--personality <name> | --profile <file>] [--seeds <count>] [--first-seed <n>] [--minutes <m>] [--difficulty <0-1>] [--episodes]"
+    );
+    std::process::exit(2);
+}
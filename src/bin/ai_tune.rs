@@ -0,0 +1,80 @@
+// Offline AI tuning with a genetic algorithm over headless games:
+//
+//   ai_tune [--generations <n>] [--population <n>] [--seeds <count>] [--minutes <m>]
+//           [--death-penalty <points>] [--rng-seed <n>] [--out <file>]
+//
+// The best profile is written to --out (default best_ai_profile.txt); point the
+// screensaver at it with `ai_profile = <file>` in the config.
+
+use asteroids_screensaver::config;
+use asteroids_screensaver::tuner::{self, TunerSettings};
+use std::path::PathBuf;
+
+fn main() {
+    let mut settings = TunerSettings::default();
+    let mut out = PathBuf::from("best_ai_profile.txt");
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--generations" => settings.generations = parse_next(&mut args, "--generations"),
+            "--population" => settings.population = parse_next(&mut args, "--population"),
+            "--seeds" => {
+                let count: u64 = parse_next(&mut args, "--seeds");
+                settings.seeds = (1..=count).collect();
+            }
+            "--minutes" => {
+                let minutes: f32 = parse_next(&mut args, "--minutes");
+                if !(minutes.is_finite() && minutes > 0.0) {
+                    exit_with_usage("--minutes expects a positive number");
+                }
+                settings.duration = minutes * 60.0;
+            }
+            "--death-penalty" => settings.death_penalty = parse_next(&mut args, "--death-penalty"),
+            "--rng-seed" => settings.rng_seed = parse_next(&mut args, "--rng-seed"),
+            "--out" => match args.next() {
+                Some(path) => out = PathBuf::from(path),
+                None => exit_with_usage("--out expects a file path"),
+            },
+            _ => exit_with_usage(&format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    let result = tuner::tune(&settings, |generation, best| {
+        println!("generation {:>3}: best fitness {:.1}", generation, best);
+    });
+
+    println!();
+    print!("{}", result.report);
+
+    let header = format!(
+        "AI profile tuned by ai_tune: fitness {:.1} over {} seeds x {:.0}s, {} generations of {}",
+        result.fitness,
+        settings.seeds.len(),
+        settings.duration,
+        settings.generations,
+        settings.population
+    );
+    match config::save_ai_profile(&result.best, &header, &out) {
+        Ok(()) => println!("\nBest profile written to {}", out.display()),
+        Err(e) => {
+            eprintln!("Could not write {}: {}", out.display(), e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn parse_next<T: std::str::FromStr>(args: &mut impl Iterator<Item = String>, flag: &str) -> T {
+    match args.next().and_then(|value| value.parse().ok()) {
+        Some(value) => value,
+        None => exit_with_usage(&format!("{} expects a number", flag)),
+    }
+}
+
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("{}", message);
+    eprintln!(
+        "Usage: ai_tune [--generations <n>] [--population <n>] [--seeds <count>] [--minutes <m>] [--death-penalty <points>] [--rng-seed <n>] [--out <file>]"
+    );
+    std::process::exit(2);
+}
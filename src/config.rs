@@ -0,0 +1,540 @@
+// User configuration, read from a plain `key = value` text file:
+//
+//   # Lines starting with '#' are comments, as is a '#' after a space outside quotes
+//   ai_personality = hunter   # classic, sniper, brawler, hunter, pacifist or rotate
+//   ai_profile = ~/tuned.txt  # AI parameters written by ai_tune (overrides ai_personality)
+//   play = true
+//   idle_seconds = 10
+//   difficulty = auto         # auto adapts to the game; 0.0 (calm) to 1.0 (frantic) fixes it
+//   debug = false             # show the debug overlay
+//   stats = true              # show the rotating session/lifetime stats page
+//   stats_file = ~/stats.txt  # where lifetime stats are kept
+//   hud_hide = thrust, power  # HUD instruments to hide: score, lives, wave, stats, direct, thrust, power, radar
+//   clock = 24                # off, 12 or 24 hour clock overlay
+//   clock_seconds = false
+//   clock_date = false        # date line under the time
+//   clock_position = bottom_right  # top_left, top, top_right, left, center, right, bottom_left, bottom, bottom_right
+//   clock_size = 0.05
+//   clock_ghost = false       # large faint digits behind the play field
+//   marquee = BACK AT 3PM     # message shown over the game
+//   marquee_file = ~/msg.txt  # message file, re-read when it changes (overrides marquee)
+//   marquee_mode = scroll     # scroll or flash
+//   marquee_speed = 0.3       # scroll: screen units per second; flash: flashes per second
+//   marquee_position = bottom # top, center or bottom
+//   marquee_size = 0.06
+//   marquee_repeat = forever  # forever, or the number of times to show the message
+//   marquee_gap = 10          # seconds between showings
+//   feed = ~/feed.jsonl       # live items as JSON lines: a watched file, or unix:/path for a socket
+//   feed_events = ~/acks.jsonl  # where acknowledgements are appended (file default: feed.events.jsonl)
+//   feed_max = 6              # labelled asteroids in play at once
+//   feed_ai_shoots = false    # let the AI aim at labelled asteroids instead of leaving them for a person
+//   profile = false           # frame-time graph (toggle with F4)
+//   profile_trace = ~/trace.json  # Chrome trace of the last 600 frames, written on exit
+//   attract = true            # cycle title, high score and demo screens
+//   demo_seconds = 90         # demo play time between title sequences (at least 1.2)
+//
+// The file is looked up in $ASTEROIDS_CONFIG, then ~/.config/asteroids-screensaver/config.
+// Missing files fall back to defaults; bad lines are reported and skipped.
+
+use crate::attract::{self, Attract};
+use crate::feed::Feed;
+use crate::game::{AiPersonality, AiProfile, GameState, Stats};
+use crate::hud::{Anchor, ClockFormat, Hud};
+use crate::marquee::{Marquee, MarqueeMode};
+use std::path::{Path, PathBuf};
+
+pub struct Config {
+    pub ai_personality: AiPersonality,
+    pub rotate_personalities: bool, // "ai_personality = rotate": new personality each life
+    pub ai_profile: Option<AiProfile>, // Custom parameters loaded from an `ai_profile` file
+    pub play_mode: bool,
+    pub ai_takeover_delay: f32,
+    pub difficulty: Option<f32>, // Fixed difficulty level, or None to adapt
+    pub show_debug: bool,
+    pub show_stats: bool,
+    pub stats_path: Option<PathBuf>, // Lifetime stats file, if not the default
+    pub hud_hide: Vec<String>,       // Names of HUD elements to hide
+    pub show_clock: bool,
+    pub clock_format: ClockFormat,
+    pub clock_anchor: Anchor,
+    pub clock_size: f32,
+    pub clock_ghost: bool, // Large faint time behind the game
+    pub marquee_text: String,
+    pub marquee_file: Option<PathBuf>,
+    pub marquee_mode: MarqueeMode,
+    pub marquee_speed: f32,
+    pub marquee_y: f32,
+    pub marquee_size: f32,
+    pub marquee_repeat: Option<u32>, // None repeats forever
+    pub marquee_gap: f32,
+    pub feed_path: Option<PathBuf>,
+    pub feed_socket: bool, // "feed = unix:/path": listen on a socket rather than watch a file
+    pub feed_events: Option<PathBuf>,
+    pub feed_max: usize,
+    pub feed_ai_shoots: bool, // The AI may aim at labelled asteroids
+    pub show_profiler: bool,
+    pub profile_trace: Option<PathBuf>,
+    pub attract_mode: bool,
+    pub demo_time: f32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            ai_personality: AiPersonality::Classic,
+            rotate_personalities: false,
+            ai_profile: None,
+            play_mode: false,
+            ai_takeover_delay: 10.0,
+            difficulty: None,
+            show_debug: false,
+            show_stats: true,
+            stats_path: None,
+            hud_hide: Vec::new(),
+            show_clock: false,
+            clock_format: ClockFormat::default(),
+            clock_anchor: Anchor::BottomRight,
+            clock_size: 0.05,
+            clock_ghost: false,
+            marquee_text: String::new(),
+            marquee_file: None,
+            marquee_mode: MarqueeMode::Scroll,
+            marquee_speed: 0.3,
+            marquee_y: -0.85,
+            marquee_size: 0.06,
+            marquee_repeat: None,
+            marquee_gap: 10.0,
+            feed_path: None,
+            feed_socket: false,
+            feed_events: None,
+            feed_max: 6,
+            feed_ai_shoots: false,
+            show_profiler: false,
+            profile_trace: None,
+            attract_mode: true,
+            demo_time: 90.0,
+        }
+    }
+}
+
+impl Config {
+    pub fn default_path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os("ASTEROIDS_CONFIG") {
+            return Some(PathBuf::from(path));
+        }
+        std::env::var_os("HOME").map(|home| {
+            PathBuf::from(home)
+                .join(".config")
+                .join("asteroids-screensaver")
+                .join("config")
+        })
+    }
+
+    pub fn load(path: &Path) -> std::io::Result<Config> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(Self::parse(&text, path))
+    }
+
+    // Load from the given path (or the default location), using defaults if there is no file
+    pub fn load_or_default(path: Option<&Path>) -> Config {
+        let Some(path) = path.map(Path::to_path_buf).or_else(Self::default_path) else {
+            return Config::default();
+        };
+
+        match Self::load(&path) {
+            Ok(config) => config,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Config::default(),
+            Err(e) => {
+                eprintln!("Could not read config {}: {}", path.display(), e);
+                Config::default()
+            }
+        }
+    }
+
+    // `source` names the file in error messages
+    pub fn parse(text: &str, source: &Path) -> Config {
+        let mut config = Config::default();
+
+        for (line, key, value) in parse_key_values(text, source) {
+            let valid = match key {
+                "ai_personality" => {
+                    if value.eq_ignore_ascii_case("rotate") {
+                        config.rotate_personalities = true;
+                        true
+                    } else if let Some(personality) = AiPersonality::from_name(value) {
+                        config.ai_personality = personality;
+                        config.rotate_personalities = false;
+                        true
+                    } else {
+                        false
+                    }
+                }
+                "ai_profile" => match load_ai_profile(&expand_home(value)) {
+                    Ok(profile) => {
+                        config.ai_profile = Some(profile);
+                        true
+                    }
+                    Err(e) => {
+                        eprintln!("Could not read AI profile {}: {}", value, e);
+                        false
+                    }
+                },
+                "play" => parse_into(value, &mut config.play_mode),
+                "idle_seconds" => parse_into(value, &mut config.ai_takeover_delay),
+                "difficulty" => {
+                    if value.eq_ignore_ascii_case("auto") {
+                        config.difficulty = None;
+                        true
+                    } else {
+                        match value.parse::<f32>() {
+                            Ok(level) if (0.0..=1.0).contains(&level) => {
+                                config.difficulty = Some(level);
+                                true
+                            }
+                            _ => false,
+                        }
+                    }
+                }
+                "debug" => parse_into(value, &mut config.show_debug),
+                "stats" => parse_into(value, &mut config.show_stats),
+                "attract" => parse_into(value, &mut config.attract_mode),
+                "demo_seconds" => parse_into(value, &mut config.demo_time),
+                "hud_hide" => {
+                    config.hud_hide = value
+                        .split(',')
+                        .map(|name| name.trim().to_ascii_lowercase())
+                        .filter(|name| !name.is_empty())
+                        .collect();
+                    true
+                }
+                "clock" => match value.to_ascii_lowercase().as_str() {
+                    "off" | "false" => {
+                        config.show_clock = false;
+                        true
+                    }
+                    "12" => {
+                        config.show_clock = true;
+                        config.clock_format.hour_24 = false;
+                        true
+                    }
+                    "24" | "true" => {
+                        config.show_clock = true;
+                        config.clock_format.hour_24 = true;
+                        true
+                    }
+                    _ => false,
+                },
+                "clock_seconds" => parse_into(value, &mut config.clock_format.seconds),
+                "clock_date" => parse_into(value, &mut config.clock_format.date),
+                "clock_position" => match Anchor::from_name(value) {
+                    Some(anchor) => {
+                        config.clock_anchor = anchor;
+                        true
+                    }
+                    None => false,
+                },
+                "clock_size" => parse_into(value, &mut config.clock_size),
+                "clock_ghost" => parse_into(value, &mut config.clock_ghost),
+                "marquee" => {
+                    config.marquee_text = value.trim_matches('"').to_string();
+                    true
+                }
+                "marquee_file" => {
+                    config.marquee_file = Some(expand_home(value));
+                    true
+                }
+                "marquee_mode" => match MarqueeMode::from_name(value) {
+                    Some(mode) => {
+                        config.marquee_mode = mode;
+                        true
+                    }
+                    None => false,
+                },
+                "marquee_speed" => parse_into(value, &mut config.marquee_speed),
+                "marquee_position" => match Marquee::position_y(value) {
+                    Some(y) => {
+                        config.marquee_y = y;
+                        true
+                    }
+                    None => false,
+                },
+                "marquee_size" => parse_into(value, &mut config.marquee_size),
+                "marquee_repeat" => {
+                    if value.eq_ignore_ascii_case("forever") {
+                        config.marquee_repeat = None;
+                        true
+                    } else {
+                        match value.parse::<u32>() {
+                            Ok(count) => {
+                                config.marquee_repeat = Some(count);
+                                true
+                            }
+                            Err(_) => false,
+                        }
+                    }
+                }
+                "marquee_gap" => parse_into(value, &mut config.marquee_gap),
+                "feed" => {
+                    config.feed_socket = value.starts_with("unix:");
+                    let path = value.strip_prefix("unix:").unwrap_or(value);
+                    config.feed_path = Some(expand_home(path));
+                    true
+                }
+                "feed_events" => {
+                    config.feed_events = Some(expand_home(value));
+                    true
+                }
+                "feed_max" => parse_into(value, &mut config.feed_max),
+                "feed_ai_shoots" => parse_into(value, &mut config.feed_ai_shoots),
+                "profile" => parse_into(value, &mut config.show_profiler),
+                "profile_trace" => {
+                    config.profile_trace = Some(expand_home(value));
+                    true
+                }
+                "stats_file" => {
+                    config.stats_path = Some(expand_home(value));
+                    true
+                }
+                _ => {
+                    eprintln!(
+                        "Config {} line {}: unknown setting '{}'",
+                        source.display(),
+                        line,
+                        key
+                    );
+                    continue;
+                }
+            };
+
+            if !valid {
+                eprintln!(
+                    "Config {} line {}: invalid value '{}' for {}",
+                    source.display(),
+                    line,
+                    value,
+                    key
+                );
+            }
+        }
+
+        config
+    }
+
+    pub fn apply(&self, game_state: &mut GameState) {
+        game_state.set_personality(self.ai_personality);
+        game_state.rotate_personalities = self.rotate_personalities;
+        if let Some(profile) = self.ai_profile {
+            // A tuned profile replaces the personality for every life
+            game_state.ai_profile = profile;
+            game_state.rotate_personalities = false;
+        }
+        game_state.play_mode = self.play_mode;
+        game_state.ai_takeover_delay = self.ai_takeover_delay;
+        if let Some(level) = self.difficulty {
+            game_state.difficulty.level = level;
+            game_state.difficulty.adaptive = false;
+        }
+        game_state.show_debug = self.show_debug;
+        game_state.ai_shoots_labels = self.feed_ai_shoots;
+    }
+
+    pub fn apply_hud(&self, hud: &mut Hud) {
+        hud.set_visible("stats", self.show_stats);
+        for name in &self.hud_hide {
+            if !hud.set_visible(name, false) {
+                eprintln!("Config: no HUD element named '{}'", name);
+            }
+        }
+        if self.show_clock {
+            hud.add_clock(
+                self.clock_format,
+                self.clock_anchor,
+                self.clock_size,
+                self.clock_ghost,
+            );
+        }
+    }
+
+    pub fn apply_attract(&self, attract: &mut Attract) {
+        attract.enabled = self.attract_mode;
+        attract.demo_time = self.demo_time.max(attract::MIN_DEMO_TIME);
+    }
+
+    pub fn apply_marquee(&self, marquee: &mut Marquee) {
+        marquee.text = self.marquee_text.clone();
+        marquee.file = self.marquee_file.clone();
+        marquee.mode = self.marquee_mode;
+        marquee.speed = self.marquee_speed;
+        marquee.y = self.marquee_y;
+        marquee.size = self.marquee_size;
+        marquee.repeat = self.marquee_repeat;
+        marquee.gap = self.marquee_gap;
+    }
+
+    // The live feed, if one is configured and could be opened
+    pub fn open_feed(&self) -> Option<Feed> {
+        let path = self.feed_path.as_ref()?;
+        let mut feed = if self.feed_socket {
+            match Feed::listen(path) {
+                Ok(feed) => feed,
+                Err(e) => {
+                    eprintln!("Could not listen on feed socket {}: {}", path.display(), e);
+                    return None;
+                }
+            }
+        } else {
+            Feed::watch_file(path)
+        };
+
+        // File feeds write events next to the feed; socket feeds send them to clients
+        feed.events_path = self
+            .feed_events
+            .clone()
+            .or_else(|| (!self.feed_socket).then(|| path.with_extension("events.jsonl")));
+        feed.max_on_field = self.feed_max;
+        Some(feed)
+    }
+
+    // The stats_file setting, or ~/.config/asteroids-screensaver/stats
+    pub fn lifetime_stats_path(&self) -> Option<PathBuf> {
+        self.stats_path.clone().or_else(|| {
+            std::env::var_os("HOME").map(|home| {
+                PathBuf::from(home)
+                    .join(".config")
+                    .join("asteroids-screensaver")
+                    .join("stats")
+            })
+        })
+    }
+}
+
+// AI profiles use the same `key = value` format, one line per parameter. Parameters
+// missing from the file keep their classic values.
+pub fn load_ai_profile(path: &Path) -> std::io::Result<AiProfile> {
+    let text = std::fs::read_to_string(path)?;
+    let mut profile = AiProfile::default();
+
+    for (line, key, value) in parse_key_values(&text, path) {
+        let valid = value
+            .parse()
+            .is_ok_and(|value| profile.set_parameter(key, value));
+        if !valid {
+            eprintln!(
+                "AI profile {} line {}: invalid setting '{} = {}'",
+                path.display(),
+                line,
+                key,
+                value
+            );
+        }
+    }
+
+    Ok(profile)
+}
+
+pub fn save_ai_profile(profile: &AiProfile, header: &str, path: &Path) -> std::io::Result<()> {
+    let mut text = String::new();
+    for line in header.lines() {
+        text.push_str(&format!("# {}\n", line));
+    }
+    for (name, value) in AiProfile::PARAMETER_NAMES.iter().zip(profile.parameters()) {
+        text.push_str(&format!("{} = {}\n", name, value));
+    }
+    std::fs::write(path, text)
+}
+
+// Lifetime stats use the `key = value` format too; unknown lines are reported and skipped
+pub fn load_stats(path: &Path) -> std::io::Result<Stats> {
+    let text = std::fs::read_to_string(path)?;
+    let mut stats = Stats::default();
+
+    for (line, key, value) in parse_key_values(&text, path) {
+        let valid = value.parse().is_ok_and(|value| stats.set_value(key, value));
+        if !valid {
+            eprintln!(
+                "Stats {} line {}: invalid setting '{} = {}'",
+                path.display(),
+                line,
+                key,
+                value
+            );
+        }
+    }
+
+    Ok(stats)
+}
+
+// Write the stats, creating the directory if needed
+pub fn save_stats(stats: &Stats, path: &Path) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut text = String::from("# Lifetime statistics, updated by the screensaver\n");
+    for (name, value) in stats.values() {
+        text.push_str(&format!("{} = {}\n", name, value));
+    }
+    std::fs::write(path, text)
+}
+
+// Expand a leading "~/" to the home directory
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+// Yields (line number, key, value) for every `key = value` line, skipping blanks and
+// comments. Other lines are reported with `source`, the file they came from.
+pub(crate) fn parse_key_values<'a>(
+    text: &'a str,
+    source: &'a Path,
+) -> impl Iterator<Item = (usize, &'a str, &'a str)> {
+    text.lines().enumerate().filter_map(|(index, line)| {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            return None;
+        }
+
+        match line.split_once('=') {
+            Some((key, value)) => Some((index + 1, key.trim(), value.trim())),
+            None => {
+                eprintln!(
+                    "{} line {}: expected 'key = value'",
+                    source.display(),
+                    index + 1
+                );
+                None
+            }
+        }
+    })
+}
+
+// Everything before a comment. A '#' starts one only at the beginning of the line or
+// after whitespace, and not inside double quotes, so `marquee = "WE'RE #1"` and
+// `marquee = ISSUE#42` keep their '#'.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    let mut previous = ' ';
+    for (index, ch) in line.char_indices() {
+        match ch {
+            '"' => quoted = !quoted,
+            '#' if !quoted && previous.is_whitespace() => return &line[..index],
+            _ => {}
+        }
+        previous = ch;
+    }
+    line
+}
+
+// Parse `value` into `target`, returning false (and leaving target alone) if it is invalid
+pub(crate) fn parse_into<T: std::str::FromStr>(value: &str, target: &mut T) -> bool {
+    match value.parse() {
+        Ok(parsed) => {
+            *target = parsed;
+            true
+        }
+        Err(_) => false,
+    }
+}
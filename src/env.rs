@@ -0,0 +1,240 @@
+// Gym-style reinforcement learning environment around the headless game. An agent
+// drives the player ship one step at a time:
+//
+//   let mut env = Env::new(EnvSettings::default());
+//   let mut observation = env.reset(42);
+//   loop {
+//       let (next, reward, done, info) = env.step(policy(&observation));
+//       if done { break; }
+//       observation = next;
+//   }
+//
+// Each step runs a fixed number of simulation ticks with the action held, so episodes
+// replay identically for the same seed and actions.
+
+use crate::game::{physics, GameState, ShipInput, ThreatKind};
+use crate::grid::{self, Grid, GridSettings};
+
+// The agent has the same controls as a keyboard player
+pub type Action = ShipInput;
+
+// Flat feature vector; see `Env::observation_size` for the layout
+pub type Observation = Vec<f32>;
+
+// Ship features: x, y, vx, vy, sin(angle), cos(angle), energy, can shoot, can hyperspace
+pub const SHIP_FEATURES: usize = 9;
+// Per object: present, dx, dy, relative vx, relative vy, radius, asteroid, saucer, bullet
+pub const OBJECT_FEATURES: usize = 9;
+
+// rotate (left, none, right) x thrust x fire, plus hyperspace
+pub const DISCRETE_ACTIONS: usize = 13;
+
+pub struct EnvSettings {
+    pub delta_time: f32,        // Fixed simulation tick
+    pub ticks_per_step: u32,    // Ticks the action is held for each step
+    pub max_steps: u32,         // Episode is truncated after this many steps
+    pub nearest_objects: usize, // Objects included in the observation, nearest first
+    pub end_on_death: bool,     // Episode ends when the ship dies
+    pub score_reward: f32,      // Reward per point scored
+    pub survival_reward: f32,   // Reward per step survived
+    pub death_penalty: f32,     // Subtracted from the reward when the ship dies
+}
+
+impl Default for EnvSettings {
+    fn default() -> Self {
+        Self {
+            delta_time: 1.0 / 60.0,
+            ticks_per_step: 4,
+            max_steps: 18_000 / 4, // 5 minutes at 60 ticks per second
+            nearest_objects: 8,
+            end_on_death: true,
+            score_reward: 0.01,
+            survival_reward: 0.01,
+            death_penalty: 10.0,
+        }
+    }
+}
+
+// Extra information about a step, not meant as input to the agent
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StepInfo {
+    pub score: u32,
+    pub deaths: u32,
+    pub steps: u32,
+    pub died: bool,      // The ship died during this step
+    pub truncated: bool, // The episode hit max_steps rather than ending naturally
+}
+
+pub struct Env {
+    pub settings: EnvSettings,
+    pub game: GameState,
+    steps: u32,
+    done: bool,
+}
+
+impl Env {
+    pub fn new(settings: EnvSettings) -> Self {
+        let mut env = Self {
+            settings,
+            game: GameState::with_seed(0),
+            steps: 0,
+            done: false,
+        };
+        env.reset(0);
+        env
+    }
+
+    pub fn observation_size(&self) -> usize {
+        SHIP_FEATURES + self.settings.nearest_objects * OBJECT_FEATURES
+    }
+
+    // Start a new episode; the same seed always produces the same game
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        let mut game = GameState::with_seed(seed);
+        // The agent drives the ship for the whole episode: the AI never takes over
+        game.play_mode = true;
+        game.ai_takeover_delay = f32::INFINITY;
+        game.set_input(ShipInput::default());
+
+        self.game = game;
+        self.steps = 0;
+        self.done = false;
+        self.observe()
+    }
+
+    // Hold `action` for one step. Stepping a finished episode returns the final
+    // observation with no reward until `reset` is called.
+    pub fn step(&mut self, action: Action) -> (Observation, f32, bool, StepInfo) {
+        if self.done {
+            return (self.observe(), 0.0, true, self.info(false, false));
+        }
+
+        let score_before = self.game.score;
+        let deaths_before = self.game.deaths;
+
+        self.game.set_input(action);
+        for _ in 0..self.settings.ticks_per_step {
+            self.game.update(self.settings.delta_time);
+            if self.game.deaths != deaths_before && self.settings.end_on_death {
+                break;
+            }
+        }
+        self.steps += 1;
+
+        let died = self.game.deaths != deaths_before;
+        let scored = self.game.score - score_before;
+        let mut reward = scored as f32 * self.settings.score_reward;
+        if died {
+            reward -= self.settings.death_penalty;
+        } else {
+            reward += self.settings.survival_reward;
+        }
+
+        let truncated = self.steps >= self.settings.max_steps;
+        self.done = truncated || (died && self.settings.end_on_death);
+
+        (
+            self.observe(),
+            reward,
+            self.done,
+            self.info(died, truncated),
+        )
+    }
+
+    fn info(&self, died: bool, truncated: bool) -> StepInfo {
+        StepInfo {
+            score: self.game.score,
+            deaths: self.game.deaths,
+            steps: self.steps,
+            died,
+            truncated,
+        }
+    }
+
+    // Ship state followed by the nearest threats relative to the ship, in wrapped
+    // coordinates. Missing objects are all zeros, so the vector length never changes.
+    pub fn observe(&self) -> Observation {
+        let ship = &self.game.player_ship;
+        let mut observation = Vec::with_capacity(self.observation_size());
+        observation.extend_from_slice(&[
+            ship.x,
+            ship.y,
+            ship.vx,
+            ship.vy,
+            ship.angle.sin(),
+            ship.angle.cos(),
+            ship.energy,
+            if ship.can_shoot() { 1.0 } else { 0.0 },
+            if ship.can_hyperspace() { 1.0 } else { 0.0 },
+        ]);
+
+        let mut objects: Vec<_> = self
+            .game
+            .threats()
+            .into_iter()
+            .map(|threat| {
+                let dx = physics::wrapped_delta(ship.x, threat.x);
+                let dy = physics::wrapped_delta(ship.y, threat.y);
+                (dx * dx + dy * dy, dx, dy, threat)
+            })
+            .collect();
+        objects.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        for index in 0..self.settings.nearest_objects {
+            match objects.get(index) {
+                Some(&(_, dx, dy, threat)) => observation.extend_from_slice(&[
+                    1.0,
+                    dx,
+                    dy,
+                    threat.vx - ship.vx,
+                    threat.vy - ship.vy,
+                    threat.radius,
+                    if threat.kind == ThreatKind::Asteroid {
+                        1.0
+                    } else {
+                        0.0
+                    },
+                    if threat.kind == ThreatKind::Saucer {
+                        1.0
+                    } else {
+                        0.0
+                    },
+                    if threat.kind == ThreatKind::Bullet {
+                        1.0
+                    } else {
+                        0.0
+                    },
+                ]),
+                None => observation.extend_from_slice(&[0.0; OBJECT_FEATURES]),
+            }
+        }
+
+        observation
+    }
+
+    // Pixel-style alternative to `observe`: an occupancy grid of the current frame
+    pub fn observe_grid(&self, settings: &GridSettings) -> Grid {
+        grid::occupancy_grid(&self.game, settings)
+    }
+}
+
+// Map a discrete action index (0..DISCRETE_ACTIONS) to ship controls, for agents
+// with a discrete action space. Out of range indices do nothing.
+pub fn discrete_action(index: usize) -> Action {
+    if index >= DISCRETE_ACTIONS {
+        return Action::default();
+    }
+    if index == DISCRETE_ACTIONS - 1 {
+        return Action {
+            hyperspace: true,
+            ..Action::default()
+        };
+    }
+
+    Action {
+        rotate: [1.0, 0.0, -1.0][index % 3],
+        thrust: (index / 3) % 2 == 1,
+        fire: index / 6 == 1,
+        hyperspace: false,
+    }
+}
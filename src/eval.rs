@@ -0,0 +1,216 @@
+// Headless evaluation of the ship AI: runs seeded games without a window and
+// summarises how well a given AI profile plays across many seeds.
+
+use crate::game::{AiProfile, GameState};
+use std::fmt;
+
+pub struct EvalSettings {
+    pub profile: AiProfile,
+    pub duration: f32,   // Simulated seconds per game
+    pub delta_time: f32, // Fixed simulation step
+    // Fixed difficulty level (0.0 to 1.0). The adaptive controller is off, since it would
+    // make the game harder for a better AI and hide the difference between profiles.
+    pub difficulty: f32,
+}
+
+impl Default for EvalSettings {
+    fn default() -> Self {
+        Self {
+            profile: AiProfile::default(),
+            duration: 300.0,
+            delta_time: 1.0 / 60.0,
+            difficulty: 0.5,
+        }
+    }
+}
+
+// Outcome of one seeded game
+#[derive(Clone, Copy, Debug)]
+pub struct EpisodeResult {
+    pub seed: u64,
+    pub survival_time: f32, // Average seconds per life (lives cut short by the time limit count too)
+    pub deaths_per_minute: f32,
+    pub accuracy: f32, // Fraction of player shots that hit something
+    pub points_per_minute: f32,
+    pub death_loop_triggers: u32,
+}
+
+pub fn run_episode(seed: u64, settings: &EvalSettings) -> EpisodeResult {
+    let mut game = GameState::with_seed(seed);
+    game.ai_profile = settings.profile;
+    game.difficulty.level = settings.difficulty;
+    game.difficulty.adaptive = false;
+
+    let steps = (settings.duration / settings.delta_time).ceil() as u32;
+    for _ in 0..steps {
+        game.update(settings.delta_time);
+    }
+
+    let minutes = settings.duration / 60.0;
+    EpisodeResult {
+        seed,
+        survival_time: settings.duration / (game.deaths + 1) as f32,
+        deaths_per_minute: game.deaths as f32 / minutes,
+        accuracy: game.stats.accuracy(),
+        points_per_minute: game.score as f32 / minutes,
+        death_loop_triggers: game.death_loop_triggers,
+    }
+}
+
+// Distribution of one metric across episodes
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Summary {
+    pub mean: f32,
+    pub min: f32,
+    pub p10: f32,
+    pub median: f32,
+    pub p90: f32,
+    pub max: f32,
+}
+
+impl Summary {
+    pub fn from_values(values: &[f32]) -> Summary {
+        if values.is_empty() {
+            return Summary::default();
+        }
+
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f32::total_cmp);
+
+        // Nearest-rank percentile
+        let percentile = |p: f32| {
+            let rank = (p * (sorted.len() - 1) as f32).round() as usize;
+            sorted[rank]
+        };
+
+        Summary {
+            mean: sorted.iter().sum::<f32>() / sorted.len() as f32,
+            min: sorted[0],
+            p10: percentile(0.1),
+            median: percentile(0.5),
+            p90: percentile(0.9),
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+pub struct EvalReport {
+    pub episodes: Vec<EpisodeResult>,
+    pub survival_time: Summary,
+    pub deaths_per_minute: Summary,
+    pub accuracy: Summary,
+    pub points_per_minute: Summary,
+    pub death_loop_triggers: Summary,
+}
+
+impl EvalReport {
+    pub fn from_episodes(episodes: Vec<EpisodeResult>) -> EvalReport {
+        let metric = |f: fn(&EpisodeResult) -> f32| {
+            Summary::from_values(&episodes.iter().map(f).collect::<Vec<_>>())
+        };
+
+        EvalReport {
+            survival_time: metric(|e| e.survival_time),
+            deaths_per_minute: metric(|e| e.deaths_per_minute),
+            accuracy: metric(|e| e.accuracy),
+            points_per_minute: metric(|e| e.points_per_minute),
+            death_loop_triggers: metric(|e| e.death_loop_triggers as f32),
+            episodes,
+        }
+    }
+}
+
+impl fmt::Display for EvalReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} episodes", self.episodes.len())?;
+        writeln!(
+            f,
+            "{:<22} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            "metric", "mean", "min", "p10", "median", "p90", "max"
+        )?;
+
+        let rows = [
+            ("survival time (s)", &self.survival_time),
+            ("deaths / minute", &self.deaths_per_minute),
+            ("accuracy", &self.accuracy),
+            ("points / minute", &self.points_per_minute),
+            ("death loop triggers", &self.death_loop_triggers),
+        ];
+        for (name, s) in rows {
+            writeln!(
+                f,
+                "{:<22} {:>10.3} {:>10.3} {:>10.3} {:>10.3} {:>10.3} {:>10.3}",
+                name, s.mean, s.min, s.p10, s.median, s.p90, s.max
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// Run one game per seed, spread over all CPU cores. Results are in seed order and
+// identical from run to run.
+pub fn evaluate(seeds: &[u64], settings: &EvalSettings) -> EvalReport {
+    let threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    let chunk_size = seeds.len().div_ceil(threads).max(1);
+
+    let episodes = std::thread::scope(|scope| {
+        let handles: Vec<_> = seeds
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|&seed| run_episode(seed, settings))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("evaluation thread panicked"))
+            .collect()
+    });
+
+    EvalReport::from_episodes(episodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_percentiles_pick_the_nearest_rank() {
+        // Unsorted 1..=11
+        let values = [7.0, 1.0, 11.0, 4.0, 2.0, 9.0, 3.0, 10.0, 5.0, 8.0, 6.0];
+        let s = Summary::from_values(&values);
+        assert_eq!(s.mean, 6.0);
+        assert_eq!(s.min, 1.0);
+        assert_eq!(s.p10, 2.0);
+        assert_eq!(s.median, 6.0);
+        assert_eq!(s.p90, 10.0);
+        assert_eq!(s.max, 11.0);
+    }
+
+    #[test]
+    fn summary_of_one_or_no_values() {
+        let s = Summary::from_values(&[2.5]);
+        assert_eq!(
+            (s.mean, s.min, s.p10, s.median, s.p90, s.max),
+            (2.5, 2.5, 2.5, 2.5, 2.5, 2.5)
+        );
+
+        let s = Summary::from_values(&[]);
+        assert_eq!((s.mean, s.min, s.max), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn summary_median_of_an_even_count_rounds_up() {
+        let s = Summary::from_values(&[4.0, 1.0, 3.0, 2.0]);
+        assert_eq!(s.mean, 2.5);
+        assert_eq!(s.median, 3.0);
+    }
+}
@@ -0,0 +1,384 @@
+// Live feed: other programs push short items (failing builds, open incidents, calendar
+// entries) as JSON lines, either appended to a watched file or written to a Unix socket.
+// Each item enters the game as a large asteroid with its text beside it. Shooting the
+// asteroid acknowledges the item, and an event is written back as a JSON line.
+//
+//   {"id": "build-1234", "text": "NIGHTLY BUILD FAILED"}
+//   {"event": "acknowledged", "id": "build-1234", "text": "NIGHTLY BUILD FAILED", "by": "player", "time": "2026-10-18T15:04:05"}
+//
+// A line that is not a JSON object is taken as the text of an item, and an item without
+// an id gets one made from a hash of its text, so it keeps the same id across restarts.
+// Items whose id is already acknowledged in the events file are skipped, so a restart
+// does not bring back everything the feed file has ever held.
+
+use crate::game::{AsteroidLabel, GameState, ShotBy};
+use serde_json::{json, Map, Value};
+use std::collections::{HashSet, VecDeque};
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+use std::os::unix::fs::FileTypeExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+
+const CHECK_INTERVAL: f32 = 1.0; // Seconds between reads of the feed
+const MAX_TEXT_CHARS: usize = 40; // Longer item text is cut short
+const MAX_LINE_BYTES: usize = 4096; // A socket client sending a longer line is dropped
+
+enum Source {
+    File {
+        path: PathBuf,
+        offset: u64, // Bytes of the file already read
+    },
+    Socket {
+        listener: UnixListener,
+        clients: Vec<Client>,
+    },
+}
+
+struct Client {
+    stream: UnixStream,
+    buffer: Vec<u8>, // Received bytes not yet ending in a newline
+}
+
+pub struct Feed {
+    source: Source,
+    pub events_path: Option<PathBuf>, // Acknowledgements are appended here as JSON lines
+    pub max_on_field: usize,          // Labelled asteroids in play at once; the rest wait
+    pending: VecDeque<AsteroidLabel>,
+    acknowledged: Option<HashSet<String>>, // Ids already acknowledged; read from the events file on first use
+    time_since_check: f32,
+}
+
+impl Feed {
+    fn new(source: Source) -> Self {
+        Self {
+            source,
+            events_path: None,
+            max_on_field: 6,
+            pending: VecDeque::new(),
+            acknowledged: None,
+            time_since_check: CHECK_INTERVAL,
+        }
+    }
+
+    // Watch a file for appended lines. It does not need to exist yet; items already in
+    // it are read on the first check.
+    pub fn watch_file(path: &Path) -> Self {
+        Self::new(Source::File {
+            path: path.to_path_buf(),
+            offset: 0,
+        })
+    }
+
+    // Listen on a Unix socket. Events are sent back to every connected client.
+    pub fn listen(path: &Path) -> std::io::Result<Self> {
+        // A socket file left behind by an earlier run would make bind fail. Anything else
+        // at the path is left alone: it is more likely a mistake in the config.
+        if let Ok(metadata) = std::fs::symlink_metadata(path) {
+            if !metadata.file_type().is_socket() {
+                return Err(std::io::Error::new(
+                    ErrorKind::AlreadyExists,
+                    "a file that is not a socket is in the way",
+                ));
+            }
+            std::fs::remove_file(path)?;
+        }
+        let listener = UnixListener::bind(path)?;
+        listener.set_nonblocking(true)?;
+        Ok(Self::new(Source::Socket {
+            listener,
+            clients: Vec::new(),
+        }))
+    }
+
+    // Read new items, release waiting ones into the game and acknowledge shot ones
+    pub fn update(&mut self, game_state: &mut GameState, delta_time: f32) {
+        self.time_since_check += delta_time;
+        if self.time_since_check >= CHECK_INTERVAL {
+            self.time_since_check = 0.0;
+            for item in self.read_lines().iter().filter_map(|line| parse_item(line)) {
+                // A re-read file repeats items that are still waiting or in play
+                let known = self.acknowledged_ids().contains(&item.id)
+                    || self.pending.iter().any(|pending| pending.id == item.id)
+                    || game_state.has_labelled_asteroid(&item.id);
+                if !known {
+                    self.pending.push_back(item);
+                }
+            }
+        }
+
+        while game_state.labelled_asteroid_count() < self.max_on_field {
+            let Some(item) = self.pending.pop_front() else {
+                break;
+            };
+            game_state.spawn_labelled_asteroid(item);
+        }
+
+        for (label, by) in std::mem::take(&mut game_state.shot_labels) {
+            self.acknowledge(&label, by);
+        }
+    }
+
+    fn acknowledged_ids(&mut self) -> &mut HashSet<String> {
+        let events_path = &self.events_path;
+        self.acknowledged.get_or_insert_with(|| {
+            events_path
+                .as_deref()
+                .map(read_acknowledged)
+                .unwrap_or_default()
+        })
+    }
+
+    // Complete lines received since the last read
+    fn read_lines(&mut self) -> Vec<String> {
+        let mut received = Vec::new();
+
+        match &mut self.source {
+            Source::File { path, offset } => match read_appended(path, offset) {
+                Ok(bytes) => received.push(bytes),
+                Err(e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => eprintln!("Could not read feed {}: {}", path.display(), e),
+            },
+            Source::Socket { listener, clients } => {
+                loop {
+                    match listener.accept() {
+                        Ok((stream, _)) => match stream.set_nonblocking(true) {
+                            Ok(()) => clients.push(Client {
+                                stream,
+                                buffer: Vec::new(),
+                            }),
+                            Err(e) => eprintln!("Feed: could not accept client: {}", e),
+                        },
+                        Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                        Err(e) => {
+                            eprintln!("Feed: could not accept client: {}", e);
+                            break;
+                        }
+                    }
+                }
+
+                clients.retain_mut(|client| {
+                    let open = client.receive();
+                    received.push(take_complete_lines(&mut client.buffer));
+                    open
+                });
+            }
+        }
+
+        received
+            .iter()
+            .flat_map(|bytes| {
+                String::from_utf8_lossy(bytes)
+                    .lines()
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn acknowledge(&mut self, label: &AsteroidLabel, by: ShotBy) {
+        self.acknowledged_ids().insert(label.id.clone());
+
+        let time = chrono::Local::now()
+            .naive_local()
+            .format("%Y-%m-%dT%H:%M:%S");
+        let event = format!(
+            "{{\"event\": \"acknowledged\", \"id\": {}, \"text\": {}, \"by\": \"{}\", \"time\": \"{}\"}}\n",
+            json!(label.id),
+            json!(label.text),
+            by.name(),
+            time
+        );
+
+        if let Some(path) = &self.events_path {
+            let written = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut file| file.write_all(event.as_bytes()));
+            if let Err(e) = written {
+                eprintln!("Could not write feed event to {}: {}", path.display(), e);
+            }
+        }
+
+        if let Source::Socket { clients, .. } = &mut self.source {
+            // A client that has gone away is dropped
+            clients.retain_mut(|client| client.stream.write_all(event.as_bytes()).is_ok());
+        }
+    }
+}
+
+impl Client {
+    // Read whatever has arrived; false once the client has disconnected
+    fn receive(&mut self) -> bool {
+        let mut chunk = [0u8; 4096];
+        loop {
+            match self.stream.read(&mut chunk) {
+                Ok(0) => return false,
+                Ok(n) => {
+                    self.buffer.extend_from_slice(&chunk[..n]);
+                    let line_start = self
+                        .buffer
+                        .iter()
+                        .rposition(|&b| b == b'\n')
+                        .map_or(0, |end| end + 1);
+                    if self.buffer.len() - line_start > MAX_LINE_BYTES {
+                        eprintln!(
+                            "Feed: dropping client that sent a line over {} bytes",
+                            MAX_LINE_BYTES
+                        );
+                        return false;
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return true,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(_) => return false,
+            }
+        }
+    }
+}
+
+// Bytes appended to the file since `offset`, up to the last complete line. A file that
+// has shrunk was replaced or truncated, and is read again from the start.
+fn read_appended(path: &Path, offset: &mut u64) -> std::io::Result<Vec<u8>> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    if len < *offset {
+        *offset = 0;
+    }
+    if len == *offset {
+        return Ok(Vec::new());
+    }
+
+    file.seek(SeekFrom::Start(*offset))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+    let complete = take_complete_lines(&mut bytes);
+    *offset += complete.len() as u64;
+    Ok(complete)
+}
+
+// Ids of the items acknowledged in an events file
+fn read_acknowledged(path: &Path) -> HashSet<String> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == ErrorKind::NotFound => return HashSet::new(),
+        Err(e) => {
+            eprintln!("Could not read feed events {}: {}", path.display(), e);
+            return HashSet::new();
+        }
+    };
+
+    text.lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter(|event| event["event"] == "acknowledged")
+        .filter_map(|event| event["id"].as_str().map(|id| id.trim().to_string()))
+        .collect()
+}
+
+// Remove and return everything up to and including the last newline
+fn take_complete_lines(buffer: &mut Vec<u8>) -> Vec<u8> {
+    match buffer.iter().rposition(|&b| b == b'\n') {
+        Some(end) => buffer.drain(..=end).collect(),
+        None => Vec::new(),
+    }
+}
+
+fn parse_item(line: &str) -> Option<AsteroidLabel> {
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+
+    let (id, text) = if line.starts_with('{') {
+        let Ok(fields) = serde_json::from_str::<Map<String, Value>>(line) else {
+            eprintln!("Feed: ignoring invalid line '{}'", line);
+            return None;
+        };
+        // Strings and numbers are accepted, e.g. "id": 1234
+        let field = |name: &str| {
+            match fields.get(name)? {
+                Value::String(value) => Some(value.trim().to_string()),
+                Value::Number(value) => Some(value.to_string()),
+                _ => None,
+            }
+            .filter(|value| !value.is_empty())
+        };
+        let Some(text) = field("text") else {
+            eprintln!("Feed: ignoring item without text '{}'", line);
+            return None;
+        };
+        (field("id"), text)
+    } else {
+        (None, line.to_string())
+    };
+
+    Some(AsteroidLabel {
+        id: id.unwrap_or_else(|| text_id(&text)),
+        text: text.chars().take(MAX_TEXT_CHARS).collect(),
+    })
+}
+
+// An id for an item sent without one, from a 64-bit FNV-1a hash of its text. Unlike the
+// standard library's hasher it is the same in every build, so ids in an events file
+// written by an earlier version still match.
+fn text_id(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("text-{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_ids_come_from_the_text() {
+        let first = parse_item("DISK FULL ON DB-2").unwrap();
+        let again = parse_item("  DISK FULL ON DB-2  ").unwrap();
+        let other = parse_item("DISK FULL ON DB-3").unwrap();
+        assert_eq!(first.id, again.id);
+        assert_ne!(first.id, other.id);
+        assert_eq!(text_id("a"), "text-af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn json_items_are_unescaped() {
+        let item = parse_item(r#"{"id": 42, "text": "SAY \"HI\" é🚀", "tags": [1, {"a": null}]}"#)
+            .unwrap();
+        assert_eq!(item.id, "42");
+        assert_eq!(item.text, "SAY \"HI\" é🚀");
+
+        let item = parse_item(r#"{"text": "NO ID"}"#).unwrap();
+        assert_eq!(item.id, text_id("NO ID"));
+        assert!(parse_item(r#"{"id": "x"}"#).is_none());
+        assert!(parse_item(r#"{"id": "x", "text": "A"} trailing"#).is_none());
+        assert!(parse_item("   ").is_none());
+    }
+
+    #[test]
+    fn reread_items_are_not_queued_twice() {
+        let dir = std::env::temp_dir().join(format!("feed-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("feed.jsonl");
+        std::fs::write(&path, "{\"id\": \"a\", \"text\": \"A\"}\nB\n").unwrap();
+
+        let mut feed = Feed::watch_file(&path);
+        feed.max_on_field = 1;
+        let mut game_state = GameState::with_seed(1);
+        feed.update(&mut game_state, CHECK_INTERVAL);
+        assert!(game_state.has_labelled_asteroid("a"));
+        assert_eq!(feed.pending.len(), 1);
+
+        // A rewritten, shorter file is read again from the start
+        std::fs::write(&path, "{\"id\": \"a\", \"text\": \"A\"}\n").unwrap();
+        feed.update(&mut game_state, CHECK_INTERVAL);
+        std::fs::write(&path, "B\n").unwrap();
+        feed.update(&mut game_state, CHECK_INTERVAL);
+        assert_eq!(game_state.labelled_asteroid_count(), 1);
+        assert_eq!(feed.pending.len(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,97 +1,574 @@
-use super::bullet::Bullet;
-use super::ship::Ship;
+use super::asteroid::Asteroid;
+use super::bullet::{Bullet, BULLET_LIFETIME, BULLET_SPEED};
+use super::physics;
+use super::saucer::Saucer;
+use super::ship::{Ship, BURST_SHOTS, SHOT_ENERGY, THRUST_DRAIN, THRUST_SPEED, TURN_RATE};
 use crate::renderer::Color;
 use rand::Rng;
 
-pub fn update_ship_ai(ship: &mut Ship, targets: &[(f32, f32)], delta_time: f32) {
-    // Update ship physics
-    ship.update(delta_time);
+// How far ahead (seconds) threats are extrapolated
+const LOOKAHEAD_TIME: f32 = 1.5;
+// Clearance credited per second until closest approach (later threats leave time to react)
+const REACTION_BONUS: f32 = 0.1;
+// Friction stops a coasting ship within roughly this long
+const DRIFT_TIME: f32 = 0.3;
+// Number of evasive headings considered around the full circle
+const EVASION_HEADINGS: usize = 16;
+// Thrust once the ship is this close to its evasive heading
+const EVASION_TOLERANCE: f32 = 0.35;
+// The field counts as calm when predicted clearance exceeds the warning distance by this much
+const CALM_MARGIN: f32 = 0.2;
+// Idle recharging continues until the battery reaches this level
+const RECHARGE_TARGET: f32 = 0.95;
+// Targets worth at least this many points are fired on even when energy is low
+const HIGH_VALUE_POINTS: u32 = 100;
 
-    if targets.is_empty() {
-        return;
+// Tunable parameters that shape how the ship AI plays
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AiProfile {
+    pub danger_distance: f32,       // Predicted clearance that triggers evasion
+    pub warning_distance: f32,      // Predicted clearance below which the ship stops closing in
+    pub engagement_range: f32,      // Only fire at targets closer than this
+    pub thrust_usage: f32,          // Chance per frame (0-1) of thrusting towards a target
+    pub min_approach_distance: f32, // Stop closing in once this near a target
+    pub alignment_tolerance: f32,   // Radians off target that still counts as aimed
+    pub fire_probability: f32,      // Chance per frame (0-1) of firing when aimed and ready
+    pub asteroid_weight: f32,       // Target priority for asteroids
+    pub saucer_weight: f32,         // Target priority for saucers
+    pub energy_reserve: f32,        // Energy kept for evasion; only high value targets dip into it
+    pub recharge_threshold: f32,    // Below this energy, idle to recharge while the field is calm
+}
+
+impl Default for AiProfile {
+    fn default() -> Self {
+        AiPersonality::Classic.profile()
     }
+}
 
-    // Find nearest target and check if it's dangerously close
-    let mut nearest_target = targets[0];
-    let mut nearest_dist_sq = f32::MAX;
+impl AiProfile {
+    pub const PARAMETER_COUNT: usize = 11;
 
-    for &(tx, ty) in targets {
-        let dx = tx - ship.x;
-        let dy = ty - ship.y;
-        let dist_sq = dx * dx + dy * dy;
+    // Parameter names, in the order used by parameters() and from_parameters()
+    pub const PARAMETER_NAMES: [&'static str; Self::PARAMETER_COUNT] = [
+        "danger_distance",
+        "warning_distance",
+        "engagement_range",
+        "thrust_usage",
+        "min_approach_distance",
+        "alignment_tolerance",
+        "fire_probability",
+        "asteroid_weight",
+        "saucer_weight",
+        "energy_reserve",
+        "recharge_threshold",
+    ];
 
-        if dist_sq < nearest_dist_sq {
-            nearest_dist_sq = dist_sq;
-            nearest_target = (tx, ty);
+    // All parameters as a flat list (e.g. a genome for tuning)
+    pub fn parameters(&self) -> [f32; Self::PARAMETER_COUNT] {
+        [
+            self.danger_distance,
+            self.warning_distance,
+            self.engagement_range,
+            self.thrust_usage,
+            self.min_approach_distance,
+            self.alignment_tolerance,
+            self.fire_probability,
+            self.asteroid_weight,
+            self.saucer_weight,
+            self.energy_reserve,
+            self.recharge_threshold,
+        ]
+    }
+
+    pub fn from_parameters(values: [f32; Self::PARAMETER_COUNT]) -> AiProfile {
+        AiProfile {
+            danger_distance: values[0],
+            warning_distance: values[1],
+            engagement_range: values[2],
+            thrust_usage: values[3],
+            min_approach_distance: values[4],
+            alignment_tolerance: values[5],
+            fire_probability: values[6],
+            asteroid_weight: values[7],
+            saucer_weight: values[8],
+            energy_reserve: values[9],
+            recharge_threshold: values[10],
         }
     }
 
-    let (tx, ty) = nearest_target;
-    let nearest_dist = nearest_dist_sq.sqrt();
+    // Set a parameter by name, returning false if there is no such parameter
+    pub fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        let Some(index) = Self::PARAMETER_NAMES.iter().position(|&n| n == name) else {
+            return false;
+        };
+        let mut values = self.parameters();
+        values[index] = value;
+        *self = Self::from_parameters(values);
+        true
+    }
+}
 
-    // Calculate angle to target
-    let dx = tx - ship.x;
-    let dy = ty - ship.y;
-    // atan2(dy, dx) gives angle where 0=right (+X), π/2=up (+Y)
-    // Ship angle 0 means nose up (+Y in local), which is world angle π/2
-    // So ship_angle = world_angle - π/2
-    let target_angle = dy.atan2(dx) - std::f32::consts::FRAC_PI_2;
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AiPersonality {
+    Classic,  // Balanced: engages the nearest target
+    Sniper,   // Cautious: keeps its distance and fires carefully from long range
+    Brawler,  // Aggressive: charges into the field and fires freely
+    Hunter,   // Prioritises saucers over asteroids
+    Pacifist, // Dodges everything, only shoots what gets too close
+}
+
+impl AiPersonality {
+    pub const ALL: [AiPersonality; 5] = [
+        AiPersonality::Classic,
+        AiPersonality::Sniper,
+        AiPersonality::Brawler,
+        AiPersonality::Hunter,
+        AiPersonality::Pacifist,
+    ];
 
-    // Normalize angle difference
-    let mut angle_diff = target_angle - ship.angle;
-    while angle_diff > std::f32::consts::PI {
-        angle_diff -= std::f32::consts::PI * 2.0;
+    pub fn profile(&self) -> AiProfile {
+        match self {
+            AiPersonality::Classic => AiProfile {
+                danger_distance: 0.25,
+                warning_distance: 0.35,
+                engagement_range: 3.0, // Whole field
+                thrust_usage: 1.0,
+                min_approach_distance: 0.1,
+                alignment_tolerance: 0.05,
+                fire_probability: 0.3,
+                asteroid_weight: 1.0,
+                saucer_weight: 1.0,
+                energy_reserve: 0.3,
+                recharge_threshold: 0.5,
+            },
+            AiPersonality::Sniper => AiProfile {
+                danger_distance: 0.3,
+                warning_distance: 0.45,
+                engagement_range: 1.6,
+                thrust_usage: 0.15,
+                min_approach_distance: 0.6,
+                alignment_tolerance: 0.03,
+                fire_probability: 0.6,
+                asteroid_weight: 1.0,
+                saucer_weight: 1.5,
+                energy_reserve: 0.4,
+                recharge_threshold: 0.7,
+            },
+            AiPersonality::Brawler => AiProfile {
+                danger_distance: 0.18,
+                warning_distance: 0.25,
+                engagement_range: 0.6,
+                thrust_usage: 1.0,
+                min_approach_distance: 0.15,
+                alignment_tolerance: 0.08,
+                fire_probability: 0.9,
+                asteroid_weight: 1.0,
+                saucer_weight: 1.0,
+                energy_reserve: 0.15,
+                recharge_threshold: 0.3,
+            },
+            AiPersonality::Hunter => AiProfile {
+                danger_distance: 0.25,
+                warning_distance: 0.35,
+                engagement_range: 1.2,
+                thrust_usage: 0.7,
+                min_approach_distance: 0.25,
+                alignment_tolerance: 0.05,
+                fire_probability: 0.5,
+                asteroid_weight: 0.5,
+                saucer_weight: 4.0,
+                energy_reserve: 0.25,
+                recharge_threshold: 0.5,
+            },
+            AiPersonality::Pacifist => AiProfile {
+                danger_distance: 0.35,
+                warning_distance: 0.5,
+                engagement_range: 0.35,
+                thrust_usage: 0.0,
+                min_approach_distance: 1.0,
+                alignment_tolerance: 0.1,
+                fire_probability: 0.2,
+                asteroid_weight: 1.0,
+                saucer_weight: 1.0,
+                energy_reserve: 0.5,
+                recharge_threshold: 0.9,
+            },
+        }
     }
-    while angle_diff < -std::f32::consts::PI {
-        angle_diff += std::f32::consts::PI * 2.0;
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AiPersonality::Classic => "classic",
+            AiPersonality::Sniper => "sniper",
+            AiPersonality::Brawler => "brawler",
+            AiPersonality::Hunter => "hunter",
+            AiPersonality::Pacifist => "pacifist",
+        }
     }
 
-    // DANGER ZONE: Aggressive asteroid avoidance
-    let danger_distance = 0.25; // Increased from 0.15
-    let warning_distance = 0.35; // Early warning zone
-    let is_ahead = angle_diff.abs() < std::f32::consts::PI * 0.66; // Within 120° ahead (wider arc)
+    pub fn from_name(name: &str) -> Option<AiPersonality> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.name().eq_ignore_ascii_case(name))
+    }
 
-    if nearest_dist < danger_distance && is_ahead {
-        // IMMEDIATE EVASIVE MANEUVER: Turn hard perpendicular to threat
-        let evade_direction = if angle_diff > 0.0 { -1.0 } else { 1.0 }; // Turn AWAY
-        ship.rotate(evade_direction, delta_time);
-        ship.angular_velocity = 0.0;
-        // Don't thrust toward danger
-        return;
+    // The personality that follows this one when rotating per life
+    pub fn next(&self) -> AiPersonality {
+        let index = Self::ALL.iter().position(|p| p == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
     }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ThreatKind {
+    Asteroid,
+    Saucer,
+    Bullet,
+}
+
+// Anything that can kill the ship, with the motion needed to predict it
+#[derive(Clone, Copy, Debug)]
+pub struct Threat {
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub radius: f32, // Distance from the threat's centre at which the ship dies
+    pub kind: ThreatKind,
+    pub value: u32, // Points the AI expects for shooting it; 0 if it should not aim at it
+}
 
-    // WARNING ZONE: Slow down and prepare to evade
-    if nearest_dist < warning_distance && is_ahead {
-        // Don't thrust toward approaching threats
-        ship.angular_velocity = 0.0;
+impl Threat {
+    pub fn from_asteroid(asteroid: &Asteroid) -> Self {
+        Self {
+            x: asteroid.x,
+            y: asteroid.y,
+            vx: asteroid.vx,
+            vy: asteroid.vy,
+            radius: asteroid.size,
+            kind: ThreatKind::Asteroid,
+            value: asteroid.points(),
+        }
+    }
+
+    pub fn from_saucer(saucer: &Saucer) -> Self {
+        Self {
+            x: saucer.x,
+            y: saucer.y,
+            vx: saucer.vx,
+            vy: saucer.vy,
+            radius: saucer.get_collision_radius(),
+            kind: ThreatKind::Saucer,
+            value: saucer.points(),
+        }
+    }
+
+    pub fn from_bullet(bullet: &Bullet) -> Self {
+        Self {
+            x: bullet.x,
+            y: bullet.y,
+            vx: bullet.vx,
+            vy: bullet.vy,
+            radius: 0.1, // Matches the bullet-player collision check
+            kind: ThreatKind::Bullet,
+            value: 0,
+        }
+    }
+
+    // Worth aiming at: bullets, and labelled asteroids unless the AI may shoot them, are only dodged
+    pub fn is_target(&self) -> bool {
+        self.value > 0
+    }
+}
+
+// What the AI did on the last frame
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AiMode {
+    #[default]
+    Idle, // Nothing to aim at
+    Evading,
+    Attacking,
+    Recharging, // Aiming without thrusting or shooting
+}
+
+impl AiMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AiMode::Idle => "idle",
+            AiMode::Evading => "evading",
+            AiMode::Attacking => "attacking",
+            AiMode::Recharging => "recharging",
+        }
+    }
+}
+
+// Decisions the AI carries from one frame to the next, and what it last decided so the
+// debug overlay can show why the ship moves as it does
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AiState {
+    pub recharging: bool, // Idling so the battery refills
+    pub mode: AiMode,
+    pub clearance: f32, // Predicted clearance holding the current heading
+    pub target: Option<(f32, f32)>, // Position of the chosen target
+    pub heading: Option<f32>, // Angle the ship is turning to (0 = up)
+}
+
+// A candidate manoeuvre: turn to `angle`, then optionally thrust along it
+#[derive(Clone, Copy)]
+struct Plan {
+    angle: f32,
+    thrust: bool,
+}
+
+pub fn update_ship_ai(
+    ship: &mut Ship,
+    state: &mut AiState,
+    threats: &[Threat],
+    profile: &AiProfile,
+    rng: &mut impl Rng,
+    delta_time: f32,
+) {
+    // Update ship physics
+    ship.update(delta_time);
+
+    state.mode = AiMode::Idle;
+    state.target = None;
+    state.heading = None;
+    if threats.is_empty() {
+        state.recharging = false;
+        state.clearance = f32::INFINITY;
         return;
     }
 
-    // Normal behavior: aim and shoot
-    // Rotate towards target
-    if angle_diff.abs() > 0.05 {
-        // Tighter tolerance for alignment
-        let direction = if angle_diff > 0.0 { 1.0 } else { -1.0 };
-        ship.rotate(direction, delta_time);
-        // Stop angular velocity so ship stops rotating
-        ship.angular_velocity = 0.0;
+    // Predict what happens if the ship simply holds its heading
+    let hold = Plan {
+        angle: ship.angle,
+        thrust: false,
+    };
+    let hold_clearance = plan_clearance(ship, hold, threats);
+    state.clearance = hold_clearance;
+    let mut allow_thrust = hold_clearance >= profile.warning_distance;
+
+    // DANGER: something is on course to hit us - pick the manoeuvre with the most clearance
+    if hold_clearance < profile.danger_distance {
+        let best = best_evasion(ship, threats);
+        if best.thrust {
+            state.mode = AiMode::Evading;
+            state.heading = Some(best.angle);
+            let angle_diff = physics::normalize_angle(best.angle - ship.angle);
+            turn_towards(ship, angle_diff, delta_time);
+            if angle_diff.abs() < EVASION_TOLERANCE {
+                ship.thrust(delta_time);
+            }
+            return;
+        }
+        // Staying put is the safest option; keep fighting without moving
+        allow_thrust = false;
+    }
+
+    // Recharge while nothing is close: thrusting and shooting both stop the battery refilling
+    let calm = hold_clearance >= profile.warning_distance + CALM_MARGIN;
+    if state.recharging {
+        state.recharging = calm && ship.energy < RECHARGE_TARGET;
+    } else {
+        state.recharging = calm && ship.energy < profile.recharge_threshold;
+    }
+
+    // Normal behavior: aim at the highest priority target and shoot
+    let Some(target) = choose_target(ship, threats, profile) else {
+        return;
+    };
+    let dx = physics::wrapped_delta(ship.x, target.x);
+    let dy = physics::wrapped_delta(ship.y, target.y);
+    let target_dist = (dx * dx + dy * dy).sqrt();
+
+    // Lead the target so bullets arrive where it will be, falling back to
+    // aiming straight at it when no intercept exists within bullet range
+    let (nose_x, nose_y) = ship.get_nose_position();
+    let world_angle = physics::intercept(
+        physics::wrapped_delta(nose_x, target.x),
+        physics::wrapped_delta(nose_y, target.y),
+        target.vx,
+        target.vy,
+        BULLET_SPEED,
+        BULLET_LIFETIME,
+    )
+    .map_or_else(|| dy.atan2(dx), |(angle, _)| angle);
+
+    // atan2(dy, dx) gives angle where 0=right (+X), π/2=up (+Y)
+    // Ship angle 0 means nose up (+Y in local), which is world angle π/2
+    // So ship_angle = world_angle - π/2
+    let target_angle = world_angle - std::f32::consts::FRAC_PI_2;
+    let angle_diff = physics::normalize_angle(target_angle - ship.angle);
+    state.mode = if state.recharging {
+        AiMode::Recharging
     } else {
-        // Facing target - only thrust if not too close
+        AiMode::Attacking
+    };
+    state.target = Some((target.x, target.y));
+    state.heading = Some(target_angle);
+
+    if angle_diff.abs() > profile.alignment_tolerance {
+        turn_towards(ship, angle_diff, delta_time);
+    } else {
+        // Facing target - only close in if the path ahead stays clear
         ship.angular_velocity = 0.0; // Ensure no residual rotation
 
-        if nearest_dist > 0.1 {
-            // Keep safe distance
+        // Keep aiming, but spend nothing until the battery has refilled
+        if state.recharging {
+            return;
+        }
+
+        let advance = Plan {
+            angle: ship.angle,
+            thrust: true,
+        };
+        if allow_thrust
+            && ship.energy > profile.energy_reserve
+            && target_dist > profile.min_approach_distance
+            && rng.gen_bool(profile.thrust_usage.clamp(0.0, 1.0) as f64)
+            && plan_clearance(ship, advance, threats) >= profile.warning_distance
+        {
             ship.thrust(delta_time);
         }
 
+        // A full burst must leave the evasion reserve intact, unless the target is worth it
+        let burst_energy = SHOT_ENERGY * BURST_SHOTS as f32;
+        let affordable = ship.energy - burst_energy >= profile.energy_reserve;
+        let worth_it = target.value >= HIGH_VALUE_POINTS;
+
         // Random shooting to make it interesting
-        let mut rng = rand::thread_rng();
-        if ship.can_shoot() && rng.gen_bool(0.3) {
+        if target_dist < profile.engagement_range
+            && (affordable || worth_it)
+            && ship.can_shoot()
+            && rng.gen_bool(profile.fire_probability.clamp(0.0, 1.0) as f64)
+        {
             ship.shoot();
         }
     }
 }
 
+fn turn_towards(ship: &mut Ship, angle_diff: f32, delta_time: f32) {
+    let direction = if angle_diff > 0.0 { 1.0 } else { -1.0 };
+    // Don't overshoot small corrections
+    let step = (angle_diff.abs() / (TURN_RATE * delta_time)).min(1.0);
+    ship.rotate(direction * step, delta_time);
+    // Stop angular velocity so ship stops rotating
+    ship.angular_velocity = 0.0;
+}
+
+// Asteroid or saucer with the highest priority: its weight divided by its distance,
+// measured across the wrap edges
+fn choose_target<'a>(
+    ship: &Ship,
+    threats: &'a [Threat],
+    profile: &AiProfile,
+) -> Option<&'a Threat> {
+    let priority = |t: &Threat| {
+        let dx = physics::wrapped_delta(ship.x, t.x);
+        let dy = physics::wrapped_delta(ship.y, t.y);
+        let weight = match t.kind {
+            ThreatKind::Saucer => profile.saucer_weight,
+            _ => profile.asteroid_weight,
+        };
+        weight / ((dx * dx + dy * dy).sqrt() + 0.1)
+    };
+
+    threats
+        .iter()
+        .filter(|t| t.is_target())
+        .max_by(|a, b| priority(a).total_cmp(&priority(b)))
+}
+
+fn best_evasion(ship: &Ship, threats: &[Threat]) -> Plan {
+    let mut best = Plan {
+        angle: ship.angle,
+        thrust: false,
+    };
+    let mut best_score = plan_clearance(ship, best, threats);
+
+    for i in 0..EVASION_HEADINGS {
+        let offset = (i as f32 / EVASION_HEADINGS as f32) * std::f32::consts::PI * 2.0;
+        let plan = Plan {
+            angle: physics::normalize_angle(ship.angle + offset),
+            thrust: true,
+        };
+
+        // Prefer smaller turns when the clearance is about the same
+        let turn = physics::normalize_angle(plan.angle - ship.angle).abs();
+        let score = plan_clearance(ship, plan, threats) - turn * 0.01;
+        if score > best_score {
+            best_score = score;
+            best = plan;
+        }
+    }
+
+    best
+}
+
+// Smallest predicted clearance to any threat over the lookahead window if `plan` is
+// followed. The ship's path is a sequence of straight segments: drift while turning,
+// thrust for as long as the battery allows (or finish drifting), then come to rest.
+fn plan_clearance(ship: &Ship, plan: Plan, threats: &[Threat]) -> f32 {
+    let turn_time =
+        (physics::normalize_angle(plan.angle - ship.angle).abs() / TURN_RATE).min(LOOKAHEAD_TIME);
+    let drift_time = turn_time.min(DRIFT_TIME);
+
+    // The ship drifts at its current velocity until friction stops it
+    let mut segments = vec![
+        (drift_time, ship.vx, ship.vy),
+        (turn_time - drift_time, 0.0, 0.0),
+    ];
+
+    if plan.thrust && ship.energy > 0.0 {
+        let forward_angle = plan.angle + std::f32::consts::FRAC_PI_2;
+        let thrust_time = (LOOKAHEAD_TIME - turn_time).min(ship.energy / THRUST_DRAIN);
+        segments.push((
+            thrust_time,
+            forward_angle.cos() * THRUST_SPEED,
+            forward_angle.sin() * THRUST_SPEED,
+        ));
+    } else {
+        // Finish drifting if the turn was quicker than the drift
+        segments.push((DRIFT_TIME - drift_time, ship.vx, ship.vy));
+    }
+
+    let planned: f32 = segments.iter().map(|s| s.0).sum();
+    if planned < LOOKAHEAD_TIME {
+        segments.push((LOOKAHEAD_TIME - planned, 0.0, 0.0));
+    }
+
+    let mut min_clearance = f32::MAX;
+    for threat in threats {
+        let mut start_time = 0.0;
+        let mut ship_x = ship.x;
+        let mut ship_y = ship.y;
+
+        for &(duration, svx, svy) in &segments {
+            if duration <= 0.0 {
+                continue;
+            }
+
+            // Relative position at the start of this segment
+            let threat_x = threat.x + threat.vx * start_time;
+            let threat_y = threat.y + threat.vy * start_time;
+            let dx = physics::wrapped_delta(ship_x, threat_x);
+            let dy = physics::wrapped_delta(ship_y, threat_y);
+
+            let (t, dist) =
+                physics::closest_approach(dx, dy, threat.vx - svx, threat.vy - svy, duration);
+            let clearance = dist - threat.radius + REACTION_BONUS * (start_time + t);
+            min_clearance = min_clearance.min(clearance);
+
+            ship_x += svx * duration;
+            ship_y += svy * duration;
+            start_time += duration;
+        }
+    }
+
+    min_clearance
+}
+
 pub fn create_bullet_from_ship(ship: &Ship, color: Color) -> Bullet {
     let (nose_x, nose_y) = ship.get_nose_position();
     Bullet::new(nose_x, nose_y, ship.angle, ship.id, color)
@@ -1,6 +1,34 @@
-use crate::renderer::{Color, Vertex};
+use crate::renderer::layout::draw_text;
+use crate::renderer::{Color, TextStyle, Vertex};
 use rand::Rng;
 
+const LABEL_SIZE: f32 = 0.04;
+
+// A live feed item carried by an asteroid; shooting the asteroid acknowledges it
+#[derive(Clone, Debug, PartialEq)]
+pub struct AsteroidLabel {
+    pub id: String,
+    pub text: String,
+}
+
+// Who shot a labelled asteroid
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShotBy {
+    Player, // A person at the keyboard
+    Ai,     // The AI flying the player ship
+    Saucer,
+}
+
+impl ShotBy {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ShotBy::Player => "player",
+            ShotBy::Ai => "ai",
+            ShotBy::Saucer => "saucer",
+        }
+    }
+}
+
 pub struct Asteroid {
     pub x: f32,
     pub y: f32,
@@ -12,13 +40,12 @@ pub struct Asteroid {
     pub rotation: f32,
     pub rotation_speed: f32,
     pub color: Color,
+    pub label: Option<AsteroidLabel>, // Drawn beside the asteroid
     vertices_offset: Vec<(f32, f32)>,
 }
 
 impl Asteroid {
-    pub fn new(x: f32, y: f32, size_level: i32, color: Color) -> Self {
-        let mut rng = rand::thread_rng();
-
+    pub fn new(x: f32, y: f32, size_level: i32, color: Color, rng: &mut impl Rng) -> Self {
         let size = match size_level {
             3 => 0.15,
             2 => 0.10,
@@ -49,10 +76,21 @@ impl Asteroid {
             rotation: 0.0,
             rotation_speed: rng.gen_range(-1.0..1.0),
             color,
+            label: None,
             vertices_offset,
         }
     }
 
+    // Points awarded for destroying this asteroid
+    pub fn points(&self) -> u32 {
+        match self.size_level {
+            3 => 20,
+            2 => 50,
+            1 => 100,
+            _ => 20,
+        }
+    }
+
     pub fn update(&mut self, delta_time: f32) {
         self.x += self.vx * delta_time;
         self.y += self.vy * delta_time;
@@ -85,6 +123,14 @@ impl Asteroid {
             });
         }
 
+        // Label to the right, vertically centred on the asteroid
+        if let Some(label) = &self.label {
+            let style = TextStyle::label(LABEL_SIZE, self.color);
+            let x = self.x + self.size + 0.02;
+            let y = self.y - LABEL_SIZE * 0.3;
+            vertices.extend(draw_text(&label.text, x, y, &style));
+        }
+
         vertices
     }
 }
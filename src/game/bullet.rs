@@ -1,5 +1,8 @@
 use crate::renderer::{Color, Vertex};
 
+pub const BULLET_SPEED: f32 = 2.0;
+pub const BULLET_LIFETIME: f32 = 2.0; // Seconds before a bullet fades out
+
 pub struct Bullet {
     pub x: f32,
     pub y: f32,
@@ -13,17 +16,16 @@ pub struct Bullet {
 
 impl Bullet {
     pub fn new(x: f32, y: f32, angle: f32, owner_id: usize, color: Color) -> Self {
-        let speed = 2.0;
         // Ship nose points up (+Y) in local coords at angle 0
         // In standard trig, +Y is angle π/2, so we ADD π/2
         let forward_angle = angle + std::f32::consts::FRAC_PI_2;
         Self {
             x,
             y,
-            vx: forward_angle.cos() * speed,
-            vy: forward_angle.sin() * speed,
+            vx: forward_angle.cos() * BULLET_SPEED,
+            vy: forward_angle.sin() * BULLET_SPEED,
             alive: true,
-            lifetime: 2.0, // 2 second lifetime
+            lifetime: BULLET_LIFETIME,
             owner_id,
             color,
         }
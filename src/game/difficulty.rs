@@ -0,0 +1,124 @@
+// Dynamic difficulty: watches how the game is going and keeps it in an interesting
+// band, busier while the ship survives and scores easily, calmer after deaths.
+//
+// A single level from 0.0 (calm) to 1.0 (frantic) drives every spawn setting.
+
+const RATE_WINDOW: f32 = 60.0; // Seconds over which recent deaths and points are averaged
+const TARGET_DEATHS_PER_MINUTE: f32 = 1.0;
+const QUIET_POINTS_PER_MINUTE: f32 = 3000.0; // Scoring slower than this means the screen is too empty
+const ADJUST_RATE: f32 = 0.01; // Level change per second when far from the target
+const DEATH_DROP: f32 = 0.03; // Immediate level drop on any death
+const QUICK_DEATH_TIME: f32 = 3.0; // Lives shorter than this count as a death loop
+const QUICK_DEATH_DROP: f32 = 0.1; // Extra drop after a quick death
+const DEATH_LOOP_QUICK_DEATHS: u32 = 3; // Quick deaths in a row that call for clearing the spawn area
+
+pub struct Difficulty {
+    pub level: f32,      // 0.0 = calmest, 1.0 = busiest
+    pub adaptive: bool,  // When false the level stays where it is set
+    pub time_alive: f32, // Seconds since the last death
+    recent_deaths: f32,  // Deaths over the last RATE_WINDOW, decaying smoothly
+    recent_points: f32,  // Points over the last RATE_WINDOW, decaying smoothly
+    quick_deaths: u32,   // Quick deaths in a row
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Self {
+            level: 0.5,
+            adaptive: true,
+            time_alive: 0.0,
+            recent_deaths: 0.0,
+            recent_points: 0.0,
+            quick_deaths: 0,
+        }
+    }
+}
+
+impl Difficulty {
+    pub fn update(&mut self, delta_time: f32, points: u32) {
+        self.time_alive += delta_time;
+
+        let decay = (-delta_time / RATE_WINDOW).exp();
+        self.recent_deaths *= decay;
+        self.recent_points = self.recent_points * decay + points as f32;
+
+        if !self.adaptive {
+            return;
+        }
+
+        // Too many deaths pulls the level down, too few pushes it up
+        let death_error =
+            (TARGET_DEATHS_PER_MINUTE - self.deaths_per_minute()) / TARGET_DEATHS_PER_MINUTE;
+        let mut change = death_error.clamp(-1.0, 1.0) * ADJUST_RATE;
+
+        // An empty screen is boring: bring more targets, but not while deaths are above
+        // target, where the boost would cancel the easing off
+        if self.points_per_minute() < QUIET_POINTS_PER_MINUTE
+            && self.deaths_per_minute() <= TARGET_DEATHS_PER_MINUTE
+        {
+            change += ADJUST_RATE;
+        }
+
+        self.level = (self.level + change * delta_time).clamp(0.0, 1.0);
+    }
+
+    // Record a death. Returns true when the last DEATH_LOOP_QUICK_DEATHS lives were all
+    // quick: easing the level has not helped, so the caller should clear the spawn area.
+    pub fn on_death(&mut self) -> bool {
+        let quick = self.time_alive < QUICK_DEATH_TIME;
+        self.recent_deaths += 1.0;
+        self.time_alive = 0.0;
+        self.quick_deaths = if quick { self.quick_deaths + 1 } else { 0 };
+
+        if self.adaptive {
+            let drop = if quick {
+                DEATH_DROP + QUICK_DEATH_DROP
+            } else {
+                DEATH_DROP
+            };
+            self.level = (self.level - drop).max(0.0);
+        }
+
+        let death_loop = self.quick_deaths >= DEATH_LOOP_QUICK_DEATHS;
+        if death_loop {
+            self.quick_deaths = 0;
+        }
+        death_loop
+    }
+
+    pub fn deaths_per_minute(&self) -> f32 {
+        self.recent_deaths * 60.0 / RATE_WINDOW
+    }
+
+    pub fn points_per_minute(&self) -> f32 {
+        self.recent_points * 60.0 / RATE_WINDOW
+    }
+
+    // Seconds between asteroid spawns
+    pub fn asteroid_spawn_interval(&self) -> f32 {
+        self.lerp(4.5, 1.5)
+    }
+
+    pub fn max_asteroids(&self) -> usize {
+        self.lerp(8.0, 18.0).round() as usize
+    }
+
+    // Seconds between saucer spawns
+    pub fn saucer_spawn_interval(&self) -> f32 {
+        self.lerp(20.0, 6.0)
+    }
+
+    // Chance that a new saucer is a small, accurate one
+    pub fn small_saucer_chance(&self) -> f64 {
+        self.lerp(0.1, 0.5) as f64
+    }
+
+    // Small saucer aim error (radians, +/-)
+    pub fn small_saucer_aim_error(&self) -> f32 {
+        self.lerp(0.3, 0.02)
+    }
+
+    fn lerp(&self, calm: f32, busy: f32) -> f32 {
+        calm + (busy - calm) * self.level
+    }
+}
@@ -0,0 +1,35 @@
+use super::ship::Ship;
+
+// Control state for the player ship, produced by the keyboard in play mode
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ShipInput {
+    pub rotate: f32,      // -1.0 = clockwise (right), 1.0 = counter-clockwise (left)
+    pub thrust: bool,     // Forward thrust
+    pub fire: bool,       // Start a burst when the cannon is ready
+    pub hyperspace: bool, // Jump to a random location (edge-triggered)
+}
+
+impl ShipInput {
+    pub fn is_idle(&self) -> bool {
+        self.rotate == 0.0 && !self.thrust && !self.fire && !self.hyperspace
+    }
+}
+
+pub fn update_ship_input(ship: &mut Ship, input: &ShipInput, delta_time: f32) {
+    // Update ship physics
+    ship.update(delta_time);
+
+    if input.rotate != 0.0 {
+        ship.rotate(input.rotate.clamp(-1.0, 1.0), delta_time);
+        // Stop angular velocity so ship stops rotating when the key is released
+        ship.angular_velocity = 0.0;
+    }
+
+    if input.thrust {
+        ship.thrust(delta_time);
+    }
+
+    if input.fire && ship.can_shoot() {
+        ship.shoot();
+    }
+}
@@ -1,15 +1,40 @@
 mod ai;
 mod asteroid;
 mod bullet;
-mod physics;
+mod difficulty;
+mod input;
+pub mod physics;
 mod saucer;
 mod ship;
+mod stats;
 
+use crate::profiler;
 use crate::renderer::{Color, Vertex};
-pub use asteroid::Asteroid;
-pub use bullet::Bullet;
+pub use ai::{AiMode, AiPersonality, AiProfile, AiState, Threat, ThreatKind};
+pub use asteroid::{Asteroid, AsteroidLabel, ShotBy};
+pub use bullet::{Bullet, BULLET_LIFETIME, BULLET_SPEED};
+pub use difficulty::Difficulty;
+pub use input::ShipInput;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 pub use saucer::{Saucer, SaucerSize};
 pub use ship::Ship;
+pub use stats::{DeathCause, Stats};
+use std::time::{Duration, Instant};
+
+const STARTING_LIVES: u32 = 3; // Spare ships at the start of a game
+const MAX_LIVES: u32 = 9;
+const EXTRA_LIFE_POINTS: u32 = 10_000; // An extra ship for every 10,000 points
+const WAVE_SIZE: u32 = 8; // Large asteroids released per wave
+
+// Wall-clock time taken by the last update, for the debug overlay
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UpdateTimings {
+    pub frame_time: f32, // delta_time given to the update
+    pub update: Duration,
+    pub ai: Duration,
+    pub collisions: Duration,
+}
 
 pub struct GameState {
     pub player_ship: Ship,
@@ -19,16 +44,44 @@ pub struct GameState {
     pub width: f32,
     pub height: f32,
     pub score: u32,
+    pub deaths: u32,
+    pub death_loop_triggers: u32, // Times repeated quick deaths forced relief around the respawn point
+    // Arcade-style progress. Losing the last spare ship starts a new game with fresh
+    // ships and wave 1; the score keeps running, as the screensaver never stops.
+    pub lives: u32, // Spare ships
+    pub wave: u32,
+    pub games: u32, // Games started, including the current one
+    pub shot_labels: Vec<(AsteroidLabel, ShotBy)>, // Shot labels not yet handled
+    pub ai_shoots_labels: bool, // The AI aims at labelled asteroids; otherwise it leaves them for a person
+    next_extra_life: u32,
+    wave_asteroids: u32, // Large asteroids released in the current wave
+    // Play statistics: this session, and the totals of earlier sessions
+    pub stats: Stats,
+    pub lifetime_stats: Stats,
+    // Large saucer aim error (radians, +/-); small saucer accuracy follows the difficulty
+    pub large_saucer_aim_error: f32,
     time_since_asteroid_spawn: f32,
     time_since_saucer_spawn: f32,
     saucer_id_counter: usize,
-    // Death loop protection
-    deaths_in_short_time: u32,
-    time_since_last_death: f32,
-    max_asteroids: usize,
+    // Spawn rates, field size and saucer accuracy adapt to how the game is going
+    pub difficulty: Difficulty,
+    pub show_debug: bool, // Draw the debug overlay
+    pub timings: UpdateTimings,
     // Configurable colors
     pub game_color: Color, // Color for game objects (ship, asteroids, bullets, saucers)
     pub hud_color: Color,  // Color for HUD/instrument cluster
+    // Interactive play mode
+    pub play_mode: bool,        // Keyboard input may drive the player ship
+    pub ai_takeover_delay: f32, // Seconds without input before the AI takes over again
+    player_input: ShipInput,
+    time_since_input: f32,
+    // AI personality
+    pub ai_personality: AiPersonality,
+    pub ai_profile: AiProfile, // Parameters the AI currently plays with
+    pub ai_state: AiState,
+    pub rotate_personalities: bool, // Switch to the next personality after each death
+    // All randomness comes from here so seeded games replay identically
+    rng: StdRng,
 }
 
 impl Default for GameState {
@@ -39,6 +92,15 @@ impl Default for GameState {
 
 impl GameState {
     pub fn new() -> Self {
+        Self::from_rng(StdRng::from_entropy())
+    }
+
+    // A game whose spawns, shapes and AI decisions are reproducible for a given seed
+    pub fn with_seed(seed: u64) -> Self {
+        Self::from_rng(StdRng::seed_from_u64(seed))
+    }
+
+    fn from_rng(rng: StdRng) -> Self {
         // Default to arcade green for game, grey for HUD
         let game_color = Color::ARCADE_GREEN;
         let hud_color = Color::GREY;
@@ -54,50 +116,136 @@ impl GameState {
             width: 1920.0,
             height: 1080.0,
             score: 0,
+            deaths: 0,
+            death_loop_triggers: 0,
+            lives: STARTING_LIVES,
+            wave: 1,
+            games: 1,
+            next_extra_life: EXTRA_LIFE_POINTS,
+            wave_asteroids: 0,
+            shot_labels: Vec::new(),
+            ai_shoots_labels: false,
+            stats: Stats::default(),
+            lifetime_stats: Stats::default(),
+            large_saucer_aim_error: 0.4,
             time_since_asteroid_spawn: 0.0,
             time_since_saucer_spawn: 0.0,
             saucer_id_counter: 1,
-            deaths_in_short_time: 0,
-            time_since_last_death: 10.0, // Start high so first death doesn't trigger
-            max_asteroids: 12,           // Reasonable limit for playability
+            difficulty: Difficulty::default(),
+            show_debug: false,
+            timings: UpdateTimings::default(),
             game_color,
             hud_color,
+            play_mode: false,
+            ai_takeover_delay: 10.0,
+            player_input: ShipInput::default(),
+            time_since_input: f32::MAX, // Start under AI control
+            ai_personality: AiPersonality::Classic,
+            ai_profile: AiPersonality::Classic.profile(),
+            ai_state: AiState::default(),
+            rotate_personalities: false,
+            rng,
+        }
+    }
+
+    pub fn set_personality(&mut self, personality: AiPersonality) {
+        self.ai_personality = personality;
+        self.ai_profile = personality.profile();
+    }
+
+    // Hyperspace is latched until the next update uses it, so a later input in the same
+    // frame (e.g. releasing another key) does not cancel the jump
+    pub fn set_input(&mut self, input: ShipInput) {
+        self.player_input = ShipInput {
+            hyperspace: self.player_input.hyperspace || input.hyperspace,
+            ..input
+        };
+        if !input.is_idle() {
+            self.time_since_input = 0.0;
         }
     }
 
+    pub fn is_player_controlled(&self) -> bool {
+        self.play_mode && self.time_since_input < self.ai_takeover_delay
+    }
+
+    // Everything that can kill the player ship: asteroids, saucers and saucer bullets
+    pub fn threats(&self) -> Vec<Threat> {
+        let ship_id = self.player_ship.id;
+        self.asteroids
+            .iter()
+            .map(|asteroid| {
+                let mut threat = Threat::from_asteroid(asteroid);
+                if asteroid.label.is_some() && !self.ai_shoots_labels {
+                    threat.value = 0; // Dodged, not aimed at
+                }
+                threat
+            })
+            .chain(self.saucers.iter().map(Threat::from_saucer))
+            .chain(
+                self.bullets
+                    .iter()
+                    .filter(|b| b.owner_id != ship_id)
+                    .map(Threat::from_bullet),
+            )
+            .collect()
+    }
+
     pub fn update(&mut self, delta_time: f32) {
-        // Update death timer
-        self.time_since_last_death += delta_time;
+        let update_start = Instant::now();
+        let score_before = self.score;
 
         // Spawn asteroids periodically (but respect max limit)
         self.time_since_asteroid_spawn += delta_time;
-        if self.time_since_asteroid_spawn > 3.0 && self.asteroids.len() < self.max_asteroids {
+        if self.time_since_asteroid_spawn > self.difficulty.asteroid_spawn_interval()
+            && self.asteroids.len() < self.difficulty.max_asteroids()
+        {
             self.spawn_asteroid();
             self.time_since_asteroid_spawn = 0.0;
         }
 
         // Spawn saucers periodically
         self.time_since_saucer_spawn += delta_time;
-        if self.time_since_saucer_spawn > 10.0 && self.saucers.len() < 2 {
+        if self.time_since_saucer_spawn > self.difficulty.saucer_spawn_interval()
+            && self.saucers.len() < 2
+        {
             self.spawn_saucer();
             self.time_since_saucer_spawn = 0.0;
         }
 
-        // Update player ship with simple AI behavior for screensaver mode
-        let targets: Vec<_> = self
-            .asteroids
-            .iter()
-            .map(|a| (a.x, a.y))
-            .chain(self.saucers.iter().map(|s| (s.x, s.y)))
-            .collect();
+        // Held keys count as activity, so the AI only returns once everything is released
+        if self.player_input.is_idle() {
+            self.time_since_input += delta_time;
+        } else {
+            self.time_since_input = 0.0;
+        }
 
-        let ship_state_before = self.player_ship.can_shoot();
-        ai::update_ship_ai(&mut self.player_ship, &targets, delta_time);
+        if self.is_player_controlled() {
+            self.timings.ai = Duration::ZERO;
+            // Keyboard drives the ship in play mode
+            input::update_ship_input(&mut self.player_ship, &self.player_input, delta_time);
 
-        // Player shoots - check if burst was initiated
-        if ship_state_before && self.player_ship.shoot_cooldown > 0.0 {
-            // Burst initiated, shoot will be handled by update_burst
+            if self.player_input.hyperspace && self.player_ship.can_hyperspace() {
+                let x = self.rng.gen_range(-0.9..0.9);
+                let y = self.rng.gen_range(-0.9..0.9);
+                self.player_ship.hyperspace(x, y);
+            }
+        } else {
+            // Update player ship with AI behavior for screensaver mode
+            let ai_start = Instant::now();
+            let threats = self.threats();
+            ai::update_ship_ai(
+                &mut self.player_ship,
+                &mut self.ai_state,
+                &threats,
+                &self.ai_profile,
+                &mut self.rng,
+                delta_time,
+            );
+            self.timings.ai = ai_start.elapsed();
         }
+        // Hyperspace is edge-triggered: one jump per key press
+        self.player_input.hyperspace = false;
 
         // Handle burst firing - check each frame if a bullet should be fired
         if self.player_ship.update_burst() {
@@ -105,16 +253,16 @@ impl GameState {
                 &self.player_ship,
                 self.game_color,
             ));
+            self.stats.shots_fired += 1;
         }
 
         // Update saucers with AI
         let mut new_saucer_bullets = Vec::new();
         for saucer in &mut self.saucers {
-            saucer.update(delta_time);
+            saucer.update(delta_time, &mut self.rng);
 
             // Saucers shoot at player or randomly
-            use rand::Rng;
-            let mut rng = rand::thread_rng();
+            let rng = &mut self.rng;
 
             if saucer.can_shoot() {
                 let shoot_chance = match saucer.size {
@@ -123,15 +271,33 @@ impl GameState {
                 };
 
                 if rng.gen_bool(shoot_chance) {
-                    // Aim at player
-                    let dx = self.player_ship.x - saucer.x;
-                    let dy = self.player_ship.y - saucer.y;
-                    let angle = dy.atan2(dx);
+                    // Aim at player (across the wrap edges, as bullets wrap too)
+                    let dx = physics::wrapped_delta(saucer.x, self.player_ship.x);
+                    let dy = physics::wrapped_delta(saucer.y, self.player_ship.y);
+                    let (aim_angle, aim_error) = match saucer.size {
+                        // Large saucers shoot at where the player is
+                        SaucerSize::Large => (dy.atan2(dx), self.large_saucer_aim_error),
+                        // Small saucers lead the player's movement
+                        SaucerSize::Small => (
+                            physics::intercept(
+                                dx,
+                                dy,
+                                self.player_ship.vx,
+                                self.player_ship.vy,
+                                BULLET_SPEED,
+                                BULLET_LIFETIME,
+                            )
+                            .map_or_else(|| dy.atan2(dx), |(angle, _)| angle),
+                            self.difficulty.small_saucer_aim_error(),
+                        ),
+                    };
+                    let angle = aim_angle + rng.gen_range(-aim_error..=aim_error);
 
+                    // Bullet angles are in ship orientation (0 = up), so convert from world angle
                     new_saucer_bullets.push(Bullet::new(
                         saucer.x,
                         saucer.y,
-                        angle,
+                        angle - std::f32::consts::FRAC_PI_2,
                         saucer.id,
                         self.game_color,
                     ));
@@ -167,7 +333,9 @@ impl GameState {
         }
 
         // Check collisions
+        let collisions_start = Instant::now();
         self.check_collisions();
+        self.timings.collisions = collisions_start.elapsed();
 
         // Wrap player and bullets
         physics::wrap_position(&mut self.player_ship.x, &mut self.player_ship.y);
@@ -177,69 +345,145 @@ impl GameState {
         for asteroid in &mut self.asteroids {
             physics::wrap_position(&mut asteroid.x, &mut asteroid.y);
         }
+
+        while self.score >= self.next_extra_life {
+            self.lives = (self.lives + 1).min(MAX_LIVES);
+            self.next_extra_life += EXTRA_LIFE_POINTS;
+        }
+
+        self.difficulty
+            .update(delta_time, self.score.saturating_sub(score_before));
+        self.stats.update(delta_time, self.score);
+
+        self.timings.frame_time = delta_time;
+        self.timings.update = update_start.elapsed();
     }
 
-    fn spawn_saucer(&mut self) {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+    // Statistics over every session, including this one
+    pub fn lifetime_totals(&self) -> Stats {
+        self.lifetime_stats.combined(&self.stats)
+    }
 
-        let size = if rng.gen_bool(0.7) {
-            SaucerSize::Large
-        } else {
+    fn spawn_saucer(&mut self) {
+        let size = if self.rng.gen_bool(self.difficulty.small_saucer_chance()) {
             SaucerSize::Small
+        } else {
+            SaucerSize::Large
         };
 
-        self.saucers
-            .push(Saucer::new(size, self.saucer_id_counter, self.game_color));
+        self.saucers.push(Saucer::new(
+            size,
+            self.saucer_id_counter,
+            self.game_color,
+            &mut self.rng,
+        ));
         self.saucer_id_counter += 1;
     }
 
-    fn handle_player_death(&mut self) {
-        // Track death timing
-        if self.time_since_last_death < 3.0 {
-            // Death within 3 seconds of last death
-            self.deaths_in_short_time += 1;
-        } else {
-            // Reset counter if it's been a while
-            self.deaths_in_short_time = 1;
-        }
+    fn handle_player_death(&mut self, cause: DeathCause) {
+        self.deaths += 1;
+        self.stats.record_death(cause);
 
-        self.time_since_last_death = 0.0;
+        // The difficulty eases off after every death, sharply after a quick one
+        let death_loop = self.difficulty.on_death();
 
-        // If dying too frequently (3+ deaths in quick succession), clear the field
-        if self.deaths_in_short_time >= 3 {
-            self.clear_asteroid_field();
-            self.deaths_in_short_time = 0;
+        // A new life brings a new personality when rotating
+        if self.rotate_personalities {
+            self.set_personality(self.ai_personality.next());
+        }
+
+        if self.lives > 0 {
+            self.lives -= 1;
+        } else {
+            self.lives = STARTING_LIVES;
+            self.wave = 1;
+            self.wave_asteroids = 0;
+            self.games += 1;
         }
 
         self.player_ship.respawn();
+
+        // Last resort: the level only changes what spawns next, so a ship that keeps
+        // respawning into the same asteroids still needs the spawn point cleared
+        if death_loop {
+            self.clear_spawn_area();
+            self.death_loop_triggers += 1;
+        }
     }
 
-    fn clear_asteroid_field(&mut self) {
-        // Clear all asteroids to give player breathing room
-        self.asteroids.clear();
+    fn clear_spawn_area(&mut self) {
+        const SAFE_RADIUS: f32 = 0.5;
 
-        // Also clear bullets to reduce chaos
-        self.bullets.clear();
+        let (x, y) = (self.player_ship.x, self.player_ship.y);
+        let ship_id = self.player_ship.id;
+        let is_near = |ox: f32, oy: f32| {
+            let dx = physics::wrapped_delta(x, ox);
+            let dy = physics::wrapped_delta(y, oy);
+            dx * dx + dy * dy < SAFE_RADIUS * SAFE_RADIUS
+        };
+
+        // Remove nearby asteroids and saucer bullets, but leave the rest of the field.
+        // Labelled asteroids are moved to the edge of the world instead, keeping their items.
+        for asteroid in &mut self.asteroids {
+            if asteroid.label.is_some() && is_near(asteroid.x, asteroid.y) {
+                asteroid.y = physics::WORLD_HALF_SIZE;
+            }
+        }
+        self.asteroids
+            .retain(|a| a.label.is_some() || !is_near(a.x, a.y));
+        self.bullets
+            .retain(|b| b.owner_id == ship_id || !is_near(b.x, b.y));
 
         // Give a brief pause before spawning new asteroids
-        self.time_since_asteroid_spawn = -5.0; // 5 second grace period
+        self.time_since_asteroid_spawn = 0.0;
     }
 
     fn spawn_asteroid(&mut self) {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+        let x = self.rng.gen_range(-1.0..1.0);
+        let y = if self.rng.gen_bool(0.5) { -1.2 } else { 1.2 };
+
+        self.asteroids
+            .push(Asteroid::new(x, y, 3, self.game_color, &mut self.rng));
+
+        self.wave_asteroids += 1;
+        if self.wave_asteroids > WAVE_SIZE {
+            self.wave += 1;
+            self.wave_asteroids = 1;
+        }
+    }
+
+    // Release a large asteroid carrying a label from the edge of the world, outside the
+    // normal spawn timer and asteroid limit
+    pub fn spawn_labelled_asteroid(&mut self, label: AsteroidLabel) {
+        let x = self.rng.gen_range(-1.0..1.0);
+        let y = if self.rng.gen_bool(0.5) { -1.2 } else { 1.2 };
+
+        let mut asteroid = Asteroid::new(x, y, 3, self.game_color, &mut self.rng);
+        asteroid.label = Some(label);
+        self.asteroids.push(asteroid);
+    }
 
-        let x = rng.gen_range(-1.0..1.0);
-        let y = if rng.gen_bool(0.5) { -1.2 } else { 1.2 };
+    pub fn labelled_asteroid_count(&self) -> usize {
+        self.asteroids.iter().filter(|a| a.label.is_some()).count()
+    }
 
-        self.asteroids.push(Asteroid::new(x, y, 3, self.game_color));
+    pub fn has_labelled_asteroid(&self, id: &str) -> bool {
+        self.asteroids
+            .iter()
+            .any(|a| a.label.as_ref().is_some_and(|label| label.id == id))
     }
 
     fn check_collisions(&mut self) {
+        let _span = profiler::span("check_collisions");
         // Bullet-Asteroid collisions
         let mut new_asteroids = Vec::new();
         let mut score_add = 0;
+        // Player ship bullets are the AI's when nobody is at the keyboard
+        let ship_shot_by = if self.is_player_controlled() {
+            ShotBy::Player
+        } else {
+            ShotBy::Ai
+        };
 
         for bullet in &mut self.bullets {
             for asteroid in &mut self.asteroids {
@@ -254,14 +498,23 @@ impl GameState {
                 if dist_sq < asteroid.size * asteroid.size {
                     bullet.alive = false;
                     asteroid.alive = false;
+                    if bullet.owner_id == self.player_ship.id {
+                        self.stats.shots_hit += 1;
+                        self.stats.record_asteroid(asteroid.size_level);
+                    }
 
                     // Award points
-                    score_add += match asteroid.size_level {
-                        3 => 20,
-                        2 => 50,
-                        1 => 100,
-                        _ => 20,
-                    };
+                    score_add += asteroid.points();
+
+                    // The fragments are plain asteroids; the label's item is acknowledged
+                    if let Some(label) = asteroid.label.take() {
+                        let by = if bullet.owner_id == self.player_ship.id {
+                            ship_shot_by
+                        } else {
+                            ShotBy::Saucer
+                        };
+                        self.shot_labels.push((label, by));
+                    }
 
                     // Split asteroid if large enough
                     if asteroid.size_level > 1 {
@@ -270,12 +523,14 @@ impl GameState {
                             asteroid.y,
                             asteroid.size_level - 1,
                             self.game_color,
+                            &mut self.rng,
                         ));
                         new_asteroids.push(Asteroid::new(
                             asteroid.x - 0.05,
                             asteroid.y,
                             asteroid.size_level - 1,
                             self.game_color,
+                            &mut self.rng,
                         ));
                     }
                 }
@@ -303,7 +558,7 @@ impl GameState {
         }
 
         if player_died {
-            self.handle_player_death();
+            self.handle_player_death(DeathCause::Asteroid);
             return; // Skip remaining collision checks this frame
         }
 
@@ -322,12 +577,13 @@ impl GameState {
                 if dist_sq < collision_radius * collision_radius {
                     bullet.alive = false;
                     saucer.alive = false;
+                    if bullet.owner_id == self.player_ship.id {
+                        self.stats.shots_hit += 1;
+                        self.stats.record_saucer(saucer.size);
+                    }
 
                     // Award points for saucer
-                    score_add += match saucer.size {
-                        SaucerSize::Large => 200,
-                        SaucerSize::Small => 1000,
-                    };
+                    score_add += saucer.points();
                 }
             }
         }
@@ -352,7 +608,7 @@ impl GameState {
         }
 
         if player_hit_saucer {
-            self.handle_player_death();
+            self.handle_player_death(DeathCause::Saucer);
             return;
         }
 
@@ -368,7 +624,7 @@ impl GameState {
 
             if dist_sq < 0.01 {
                 bullet.alive = false;
-                self.handle_player_death();
+                self.handle_player_death(DeathCause::SaucerBullet);
                 return;
             }
         }
@@ -376,387 +632,11 @@ impl GameState {
         self.score += score_add;
     }
 
+    // Line geometry of the game objects, in world coordinates. The HUD is drawn by crate::hud.
     pub fn get_vertices(&self) -> Vec<Vertex> {
+        let _span = profiler::span("game_vertices");
         let mut vertices = Vec::new();
 
-        // Render score in top-left corner
-        use crate::renderer::{render_label, render_number};
-        vertices.extend(render_number(self.score, -0.95, 0.9, 0.06, self.hud_color));
-
-        // Calculate aspect ratio for circular compass
-        let aspect_ratio = self.width / self.height;
-
-        // ===== STACKED INDICATORS IN TOP-RIGHT =====
-        let base_x = 0.55;
-        let label_size = 0.025;
-        let value_size = 0.03;
-        let spacing = 0.12; // Vertical spacing between indicators
-        let indicator_width = 0.12; // All visual indicators same width
-        let color = self.hud_color.to_array();
-
-        // === 1. DIRECTION INDICATOR ===
-        let dir_y = 0.90;
-
-        // Label (left-aligned at base_x)
-        vertices.extend(render_label(
-            "DIRECT",
-            base_x,
-            dir_y,
-            label_size,
-            self.hud_color,
-        ));
-
-        // Direction indicator position
-        let cursor_x = base_x + 0.18 + indicator_width / 2.0; // Center of indicator area
-        let cursor_y = dir_y - 0.0125; // Vertically centered with baseline
-        let cursor_radius = 0.025;
-        let cursor_y_scale = aspect_ratio; // Scale Y to maintain aspect ratio
-
-        // Screen-shaped box frame (rectangular outline matching screen aspect)
-        let box_half_width = indicator_width / 2.0;
-        let box_half_height = box_half_width / aspect_ratio;
-
-        // Draw rectangular box frame
-        let box_left = cursor_x - box_half_width;
-        let box_right = cursor_x + box_half_width;
-        let box_top = cursor_y + box_half_height;
-        let box_bottom = cursor_y - box_half_height;
-
-        vertices.push(Vertex {
-            position: [box_left, box_top],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [box_right, box_top],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [box_right, box_top],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [box_right, box_bottom],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [box_right, box_bottom],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [box_left, box_bottom],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [box_left, box_bottom],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [box_left, box_top],
-            color,
-        });
-
-        // Circle inside the box (using 16 segments for smooth appearance)
-        let circle_segments = 16;
-        let circle_radius = cursor_radius * 0.8; // Slightly smaller than cursor range
-        for i in 0..circle_segments {
-            let angle1 = (i as f32 / circle_segments as f32) * 2.0 * std::f32::consts::PI;
-            let angle2 = ((i + 1) as f32 / circle_segments as f32) * 2.0 * std::f32::consts::PI;
-
-            let x1 = cursor_x + circle_radius * angle1.cos();
-            let y1 = cursor_y + (circle_radius / cursor_y_scale) * angle1.sin();
-            let x2 = cursor_x + circle_radius * angle2.cos();
-            let y2 = cursor_y + (circle_radius / cursor_y_scale) * angle2.sin();
-
-            vertices.push(Vertex {
-                position: [x1, y1],
-                color,
-            });
-            vertices.push(Vertex {
-                position: [x2, y2],
-                color,
-            });
-        }
-
-        // Center point (small dot) - draw as small cross
-        let dot_size = 0.002;
-        vertices.push(Vertex {
-            position: [cursor_x - dot_size, cursor_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [cursor_x + dot_size, cursor_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [cursor_x, cursor_y - dot_size / cursor_y_scale],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [cursor_x, cursor_y + dot_size / cursor_y_scale],
-            color,
-        });
-
-        // Triangular cursor that rotates around center point
-        let cursor_angle = self.player_ship.angle + std::f32::consts::FRAC_PI_2;
-
-        // Cursor tip (points in heading direction)
-        let tip_x = cursor_x + cursor_radius * cursor_angle.cos();
-        let tip_y = cursor_y + (cursor_radius / cursor_y_scale) * cursor_angle.sin();
-
-        // Cursor base (two points forming triangle base)
-        let base_angle1 = cursor_angle + std::f32::consts::PI * 0.85;
-        let base_angle2 = cursor_angle - std::f32::consts::PI * 0.85;
-        let base_radius = cursor_radius * 0.4;
-
-        let base1_x = cursor_x + base_radius * base_angle1.cos();
-        let base1_y = cursor_y + (base_radius / cursor_y_scale) * base_angle1.sin();
-        let base2_x = cursor_x + base_radius * base_angle2.cos();
-        let base2_y = cursor_y + (base_radius / cursor_y_scale) * base_angle2.sin();
-
-        // Draw triangle (tip to base1, base1 to base2, base2 to tip)
-        vertices.push(Vertex {
-            position: [tip_x, tip_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [base1_x, base1_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [base1_x, base1_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [base2_x, base2_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [base2_x, base2_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [tip_x, tip_y],
-            color,
-        });
-
-        // Numeric direction value (right-aligned at base_x + 0.35)
-        let mut degrees = self.player_ship.angle.to_degrees();
-        while degrees < 0.0 {
-            degrees += 360.0;
-        }
-        degrees %= 360.0;
-        vertices.extend(render_number(
-            degrees as u32,
-            base_x + 0.35,
-            dir_y,
-            value_size,
-            self.hud_color,
-        ));
-
-        // === 2. THRUST INDICATOR ===
-        let thrust_y = dir_y - spacing;
-
-        // Label (left-aligned at base_x)
-        vertices.extend(render_label(
-            "THRUST",
-            base_x,
-            thrust_y,
-            label_size,
-            self.hud_color,
-        ));
-
-        // Thrust bar (width = indicator_width = 0.12)
-        let thrust_bar_x = base_x + 0.18;
-        let thrust_bar_width = indicator_width;
-        let thrust_bar_height = 0.025;
-
-        // Outline
-        vertices.push(Vertex {
-            position: [thrust_bar_x, thrust_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [thrust_bar_x + thrust_bar_width, thrust_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [thrust_bar_x + thrust_bar_width, thrust_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [
-                thrust_bar_x + thrust_bar_width,
-                thrust_y - thrust_bar_height,
-            ],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [
-                thrust_bar_x + thrust_bar_width,
-                thrust_y - thrust_bar_height,
-            ],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [thrust_bar_x, thrust_y - thrust_bar_height],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [thrust_bar_x, thrust_y - thrust_bar_height],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [thrust_bar_x, thrust_y],
-            color,
-        });
-
-        // Fill based on speed
-        let speed = (self.player_ship.vx * self.player_ship.vx
-            + self.player_ship.vy * self.player_ship.vy)
-            .sqrt();
-        let thrust_fill = (thrust_bar_width - 0.005) * (speed / 1.0).min(1.0);
-        if thrust_fill > 0.0 {
-            for i in 0..3 {
-                let y = thrust_y - 0.005 - (thrust_bar_height - 0.01) * (i as f32 / 2.0);
-                vertices.push(Vertex {
-                    position: [thrust_bar_x + 0.003, y],
-                    color,
-                });
-                vertices.push(Vertex {
-                    position: [thrust_bar_x + 0.003 + thrust_fill, y],
-                    color,
-                });
-            }
-        }
-
-        // Numeric value (0-9, right-aligned at base_x + 0.35)
-        let thrust_value = ((speed * 10.0).min(9.0)) as u32;
-        vertices.extend(render_number(
-            thrust_value,
-            base_x + 0.35,
-            thrust_y,
-            value_size,
-            self.hud_color,
-        ));
-
-        // === 3. POWER INDICATOR ===
-        let power_y = thrust_y - spacing;
-
-        // Label (left-aligned at base_x)
-        vertices.extend(render_label(
-            "POWER",
-            base_x,
-            power_y,
-            label_size,
-            self.hud_color,
-        ));
-
-        // Power bar with tip (width = indicator_width = 0.12)
-        let power_bar_x = base_x + 0.18;
-        let power_bar_width = indicator_width;
-        let power_bar_height = 0.025;
-
-        // Outline
-        vertices.push(Vertex {
-            position: [power_bar_x, power_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [power_bar_x + power_bar_width, power_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [power_bar_x + power_bar_width, power_y],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [power_bar_x + power_bar_width, power_y - power_bar_height],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [power_bar_x + power_bar_width, power_y - power_bar_height],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [power_bar_x, power_y - power_bar_height],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [power_bar_x, power_y - power_bar_height],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [power_bar_x, power_y],
-            color,
-        });
-
-        // Battery tip (arrow pointing right)
-        let tip_width = 0.008;
-        let tip_height = power_bar_height * 0.4;
-        let tip_y_center = power_y - power_bar_height / 2.0;
-        vertices.push(Vertex {
-            position: [power_bar_x + power_bar_width, tip_y_center + tip_height],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [
-                power_bar_x + power_bar_width + tip_width,
-                tip_y_center + tip_height,
-            ],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [
-                power_bar_x + power_bar_width + tip_width,
-                tip_y_center + tip_height,
-            ],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [
-                power_bar_x + power_bar_width + tip_width,
-                tip_y_center - tip_height,
-            ],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [
-                power_bar_x + power_bar_width + tip_width,
-                tip_y_center - tip_height,
-            ],
-            color,
-        });
-        vertices.push(Vertex {
-            position: [power_bar_x + power_bar_width, tip_y_center - tip_height],
-            color,
-        });
-
-        // Fill based on energy
-        let power_fill = (power_bar_width - 0.005) * self.player_ship.energy;
-        if power_fill > 0.0 {
-            for i in 0..4 {
-                let y = power_y - 0.005 - (power_bar_height - 0.01) * (i as f32 / 3.0);
-                vertices.push(Vertex {
-                    position: [power_bar_x + 0.003, y],
-                    color,
-                });
-                vertices.push(Vertex {
-                    position: [power_bar_x + 0.003 + power_fill, y],
-                    color,
-                });
-            }
-        }
-
-        // Numeric value (percentage, right-aligned at base_x + 0.35)
-        let power_pct = (self.player_ship.energy * 100.0) as u32;
-        vertices.extend(render_number(
-            power_pct,
-            base_x + 0.35,
-            power_y,
-            value_size,
-            self.hud_color,
-        ));
-
         // Render player ship
         vertices.extend(self.player_ship.get_vertices());
 
@@ -1,15 +1,114 @@
+// Objects wrap around at ±1.2, slightly beyond the visible ±1.0 screen
+pub const WORLD_HALF_SIZE: f32 = 1.2;
+pub const WORLD_SIZE: f32 = WORLD_HALF_SIZE * 2.0;
+
 pub fn wrap_position(x: &mut f32, y: &mut f32) {
-    if *x > 1.2 {
-        *x = -1.2;
-    } else if *x < -1.2 {
-        *x = 1.2;
+    if *x > WORLD_HALF_SIZE {
+        *x = -WORLD_HALF_SIZE;
+    } else if *x < -WORLD_HALF_SIZE {
+        *x = WORLD_HALF_SIZE;
+    }
+
+    if *y > WORLD_HALF_SIZE {
+        *y = -WORLD_HALF_SIZE;
+    } else if *y < -WORLD_HALF_SIZE {
+        *y = WORLD_HALF_SIZE;
+    }
+}
+
+// Shortest signed distance from `from` to `to` on the wrapping world axis
+pub fn wrapped_delta(from: f32, to: f32) -> f32 {
+    let mut delta = to - from;
+    if delta > WORLD_HALF_SIZE {
+        delta -= WORLD_SIZE;
+    } else if delta < -WORLD_HALF_SIZE {
+        delta += WORLD_SIZE;
     }
+    delta
+}
 
-    if *y > 1.2 {
-        *y = -1.2;
-    } else if *y < -1.2 {
-        *y = 1.2;
+// Wrap an angle into the range -π..π
+pub fn normalize_angle(angle: f32) -> f32 {
+    let mut angle = angle;
+    while angle > std::f32::consts::PI {
+        angle -= std::f32::consts::PI * 2.0;
+    }
+    while angle < -std::f32::consts::PI {
+        angle += std::f32::consts::PI * 2.0;
     }
+    angle
+}
+
+// Time (clamped to 0..max_time) and distance of closest approach for two objects
+// separated by (dx, dy) and moving with relative velocity (dvx, dvy)
+pub fn closest_approach(dx: f32, dy: f32, dvx: f32, dvy: f32, max_time: f32) -> (f32, f32) {
+    let speed_sq = dvx * dvx + dvy * dvy;
+    let t = if speed_sq > 1e-8 {
+        (-(dx * dvx + dy * dvy) / speed_sq).clamp(0.0, max_time)
+    } else {
+        0.0
+    };
+
+    let cx = dx + dvx * t;
+    let cy = dy + dvy * t;
+    (t, (cx * cx + cy * cy).sqrt())
+}
+
+// World angle (0 = +X) to fire a projectile of the given speed so it meets a target at
+// offset (dx, dy) moving with velocity (tvx, tvy), plus the time until impact. Shots across
+// the wrap edges are considered too; the earliest hit within max_time wins.
+pub fn intercept(
+    dx: f32,
+    dy: f32,
+    tvx: f32,
+    tvy: f32,
+    projectile_speed: f32,
+    max_time: f32,
+) -> Option<(f32, f32)> {
+    let mut best: Option<(f32, f32)> = None;
+
+    for wrap_x in [-WORLD_SIZE, 0.0, WORLD_SIZE] {
+        for wrap_y in [-WORLD_SIZE, 0.0, WORLD_SIZE] {
+            let px = dx + wrap_x;
+            let py = dy + wrap_y;
+
+            // Solve |p + v·t| = speed·t for the smallest positive t
+            let a = tvx * tvx + tvy * tvy - projectile_speed * projectile_speed;
+            let b = 2.0 * (px * tvx + py * tvy);
+            let c = px * px + py * py;
+
+            let t = if a.abs() < 1e-6 {
+                if b.abs() < 1e-6 {
+                    continue;
+                }
+                -c / b
+            } else {
+                let discriminant = b * b - 4.0 * a * c;
+                if discriminant < 0.0 {
+                    continue;
+                }
+                let root = discriminant.sqrt();
+                let t1 = (-b - root) / (2.0 * a);
+                let t2 = (-b + root) / (2.0 * a);
+                match (t1 > 0.0, t2 > 0.0) {
+                    (true, true) => t1.min(t2),
+                    (true, false) => t1,
+                    (false, true) => t2,
+                    (false, false) => continue,
+                }
+            };
+
+            if t <= 0.0 || t > max_time || best.is_some_and(|(_, best_t)| best_t <= t) {
+                continue;
+            }
+
+            let aim_x = px + tvx * t;
+            let aim_y = py + tvy * t;
+            best = Some((aim_y.atan2(aim_x), t));
+        }
+    }
+
+    best
 }
 
 pub fn rotate_point(x: f32, y: f32, angle: f32) -> (f32, f32) {
@@ -17,3 +116,65 @@ pub fn rotate_point(x: f32, y: f32, angle: f32) -> (f32, f32) {
     let sin = angle.sin();
     (x * cos - y * sin, x * sin + y * cos)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEED: f32 = 1.5;
+
+    // Where a shot fired at `angle` is after `t` seconds
+    fn shot_at(angle: f32, t: f32) -> (f32, f32) {
+        (angle.cos() * SPEED * t, angle.sin() * SPEED * t)
+    }
+
+    #[test]
+    fn intercept_still_target_aims_straight_at_it() {
+        let (angle, t) = intercept(0.0, 0.6, 0.0, 0.0, SPEED, 2.0).unwrap();
+        assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+        assert!((t - 0.4).abs() < 1e-5);
+    }
+
+    #[test]
+    fn intercept_leads_a_moving_target() {
+        let (dx, dy, vx, vy) = (0.5, 0.2, -0.1, 0.3);
+        let (angle, t) = intercept(dx, dy, vx, vy, SPEED, 2.0).unwrap();
+        let (x, y) = shot_at(angle, t);
+        assert!((x - (dx + vx * t)).abs() < 1e-4);
+        assert!((y - (dy + vy * t)).abs() < 1e-4);
+        assert!(angle > dy.atan2(dx), "aims ahead of the target, not at it");
+    }
+
+    #[test]
+    fn intercept_shoots_across_the_wrap_when_that_is_nearer() {
+        // 2.0 to the right is 0.4 to the left across the edge
+        let (angle, t) = intercept(2.0, 0.0, 0.0, 0.0, SPEED, 2.0).unwrap();
+        assert!((angle.abs() - std::f32::consts::PI).abs() < 1e-5);
+        assert!((t - 0.4 / SPEED).abs() < 1e-5);
+    }
+
+    #[test]
+    fn intercept_fails_for_unreachable_targets() {
+        // Crossing faster than the shot can travel, in every wrapped copy
+        assert!(intercept(0.5, 0.0, 0.0, 10.0, SPEED, 10.0).is_none());
+        // Reachable, but not within the shot's lifetime
+        assert!(intercept(0.9, 0.0, 0.0, 0.0, SPEED, 0.5).is_none());
+    }
+
+    #[test]
+    fn closest_approach_of_passing_objects() {
+        // Passing 0.2 apart, closest after 1 second
+        let (t, distance) = closest_approach(-1.0, 0.2, 1.0, 0.0, 5.0);
+        assert!((t - 1.0).abs() < 1e-6);
+        assert!((distance - 0.2).abs() < 1e-6);
+
+        // Moving apart: closest now
+        let (t, distance) = closest_approach(0.3, 0.4, 1.0, 0.0, 5.0);
+        assert_eq!(t, 0.0);
+        assert!((distance - 0.5).abs() < 1e-6);
+
+        // Still approaching at max_time
+        let (t, _) = closest_approach(-10.0, 0.0, 1.0, 0.0, 2.0);
+        assert_eq!(t, 2.0);
+    }
+}
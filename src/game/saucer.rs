@@ -20,9 +20,7 @@ pub struct Saucer {
 }
 
 impl Saucer {
-    pub fn new(size: SaucerSize, id: usize, color: Color) -> Self {
-        let mut rng = rand::thread_rng();
-
+    pub fn new(size: SaucerSize, id: usize, color: Color, rng: &mut impl Rng) -> Self {
         // Spawn from left or right edge
         let from_left = rng.gen_bool(0.5);
         let x = if from_left { -1.2 } else { 1.2 };
@@ -51,12 +49,11 @@ impl Saucer {
         }
     }
 
-    pub fn update(&mut self, delta_time: f32) {
+    pub fn update(&mut self, delta_time: f32, rng: &mut impl Rng) {
         self.x += self.vx * delta_time;
         self.y += self.vy * delta_time;
 
         // Random direction changes
-        let mut rng = rand::thread_rng();
         if rng.gen_bool(0.02) {
             self.vy = rng.gen_range(-0.1..0.1);
         }
@@ -83,6 +80,14 @@ impl Saucer {
         };
     }
 
+    // Points awarded for destroying this saucer
+    pub fn points(&self) -> u32 {
+        match self.size {
+            SaucerSize::Large => 200,
+            SaucerSize::Small => 1000,
+        }
+    }
+
     pub fn get_size_value(&self) -> f32 {
         match self.size {
             SaucerSize::Large => 0.07,  // Increased by 40% (0.05 * 1.4 = 0.07)
@@ -1,6 +1,12 @@
 use super::physics;
 use crate::renderer::{Color, Vertex};
 
+pub const THRUST_SPEED: f32 = 0.6; // Fixed forward speed when thrusting
+pub const TURN_RATE: f32 = 3.0; // Radians per second at full rotation
+pub const THRUST_DRAIN: f32 = 0.3; // Energy drained per second of thrust
+pub const SHOT_ENERGY: f32 = 0.1; // Energy drained per bullet
+pub const BURST_SHOTS: u8 = 3; // Bullets fired per trigger pull
+
 pub struct Ship {
     pub x: f32,
     pub y: f32,
@@ -11,10 +17,11 @@ pub struct Ship {
     pub color: Color,
     pub id: usize,
     pub shoot_cooldown: f32,
-    pub thrust_level: u8,    // 0 = no thrust, 1-3 = thrust levels
-    pub energy: f32,         // Battery/energy level (0.0 to 1.0)
-    pub burst_count: u8,     // Current shot in burst (0-2)
-    pub burst_cooldown: f32, // Time between burst shots
+    pub thrust_level: u8,         // 0 = no thrust, 1-3 = thrust levels
+    pub energy: f32,              // Battery/energy level (0.0 to 1.0)
+    pub burst_count: u8,          // Current shot in burst (0-2)
+    pub burst_cooldown: f32,      // Time between burst shots
+    pub hyperspace_cooldown: f32, // Time until hyperspace is available again
     spawn_x: f32,
     spawn_y: f32,
 }
@@ -35,6 +42,7 @@ impl Ship {
             energy: 1.0, // Start with full battery
             burst_count: 0,
             burst_cooldown: 0.0,
+            hyperspace_cooldown: 0.0,
             spawn_x: x,
             spawn_y: y,
         }
@@ -46,13 +54,12 @@ impl Ship {
             // Set velocity directly in forward direction (not additive)
             // Ship nose points up (+Y) in local coords at angle 0
             // In standard trig, +Y is angle π/2, so we ADD π/2
-            let thrust_speed = 0.6; // Fixed forward speed when thrusting
             let forward_angle = self.angle + std::f32::consts::FRAC_PI_2;
-            self.vx = forward_angle.cos() * thrust_speed;
-            self.vy = forward_angle.sin() * thrust_speed;
+            self.vx = forward_angle.cos() * THRUST_SPEED;
+            self.vy = forward_angle.sin() * THRUST_SPEED;
 
             // Drain energy when thrusting
-            self.energy -= 0.3 * delta_time;
+            self.energy -= THRUST_DRAIN * delta_time;
             if self.energy < 0.0 {
                 self.energy = 0.0;
             }
@@ -80,7 +87,7 @@ impl Ship {
     }
 
     pub fn rotate(&mut self, direction: f32, delta_time: f32) {
-        self.angular_velocity = direction * 3.0;
+        self.angular_velocity = direction * TURN_RATE;
         self.angle += self.angular_velocity * delta_time;
     }
 
@@ -117,6 +124,11 @@ impl Ship {
             self.burst_cooldown -= delta_time;
         }
 
+        // Update hyperspace cooldown
+        if self.hyperspace_cooldown > 0.0 {
+            self.hyperspace_cooldown -= delta_time;
+        }
+
         // Recharge energy when not shooting or thrusting (fast recharge)
         if self.shoot_cooldown <= 0.0 && self.thrust_level == 0 {
             self.energy += 1.5 * delta_time; // Fast recharge
@@ -127,7 +139,7 @@ impl Ship {
     }
 
     pub fn can_shoot(&self) -> bool {
-        self.shoot_cooldown <= 0.0 && self.energy >= 0.1
+        self.shoot_cooldown <= 0.0 && self.energy >= SHOT_ENERGY
     }
 
     pub fn shoot(&mut self) {
@@ -139,12 +151,15 @@ impl Ship {
 
     pub fn update_burst(&mut self) -> bool {
         // Returns true if a bullet should be fired this frame
-        if self.burst_count < 3 && self.burst_cooldown <= 0.0 && self.energy >= 0.1 {
+        if self.burst_count < BURST_SHOTS
+            && self.burst_cooldown <= 0.0
+            && self.energy >= SHOT_ENERGY
+        {
             self.burst_count += 1;
             self.burst_cooldown = 0.08; // Fast shots in burst (80ms between shots)
 
             // Drain energy for each shot
-            self.energy -= 0.1;
+            self.energy -= SHOT_ENERGY;
             if self.energy < 0.0 {
                 self.energy = 0.0;
             }
@@ -154,6 +169,20 @@ impl Ship {
         false
     }
 
+    pub fn can_hyperspace(&self) -> bool {
+        self.hyperspace_cooldown <= 0.0
+    }
+
+    pub fn hyperspace(&mut self, x: f32, y: f32) {
+        // Vanish and reappear at the given location with no momentum
+        self.x = x;
+        self.y = y;
+        self.vx = 0.0;
+        self.vy = 0.0;
+        self.thrust_level = 0;
+        self.hyperspace_cooldown = 2.0; // Prevent chaining jumps
+    }
+
     pub fn respawn(&mut self) {
         self.x = self.spawn_x;
         self.y = self.spawn_y;
@@ -165,6 +194,7 @@ impl Ship {
         self.energy = 1.0; // Restore full energy on respawn
         self.burst_count = 0;
         self.burst_cooldown = 0.0;
+        self.hyperspace_cooldown = 0.0;
     }
 
     // Get the position of the ship's nose (for bullet spawning)
@@ -176,9 +206,11 @@ impl Ship {
         (self.x + nx, self.y + ny)
     }
 
-    pub fn get_vertices(&self) -> Vec<Vertex> {
-        let size = 0.024; // Reduced by 20% (0.03 * 0.8 = 0.024)
-        let color = [self.color.r, self.color.g, self.color.b, self.color.a];
+    // Ship outline without the thrust flame, at any position, angle and scale. The HUD
+    // uses it, scaled down, for the spare ships.
+    pub fn hull_vertices(x: f32, y: f32, angle: f32, scale: f32, color: Color) -> Vec<Vertex> {
+        let size = 0.024 * scale; // Reduced by 20% (0.03 * 0.8 = 0.024)
+        let color = color.to_array();
 
         // Redesigned ship with straight sides
         //       0 (nose/tip)
@@ -225,15 +257,15 @@ impl Ship {
             let (x1, y1) = points[start];
             let (x2, y2) = points[end];
 
-            let (rx1, ry1) = physics::rotate_point(x1, y1, self.angle);
-            let (rx2, ry2) = physics::rotate_point(x2, y2, self.angle);
+            let (rx1, ry1) = physics::rotate_point(x1, y1, angle);
+            let (rx2, ry2) = physics::rotate_point(x2, y2, angle);
 
             vertices.push(Vertex {
-                position: [self.x + rx1, self.y + ry1],
+                position: [x + rx1, y + ry1],
                 color,
             });
             vertices.push(Vertex {
-                position: [self.x + rx2, self.y + ry2],
+                position: [x + rx2, y + ry2],
                 color,
             });
         }
@@ -253,15 +285,15 @@ impl Ship {
             let (x1, y1) = diamond_points[start];
             let (x2, y2) = diamond_points[end];
 
-            let (rx1, ry1) = physics::rotate_point(x1, y1, self.angle);
-            let (rx2, ry2) = physics::rotate_point(x2, y2, self.angle);
+            let (rx1, ry1) = physics::rotate_point(x1, y1, angle);
+            let (rx2, ry2) = physics::rotate_point(x2, y2, angle);
 
             vertices.push(Vertex {
-                position: [self.x + rx1, self.y + ry1],
+                position: [x + rx1, y + ry1],
                 color,
             });
             vertices.push(Vertex {
-                position: [self.x + rx2, self.y + ry2],
+                position: [x + rx2, y + ry2],
                 color,
             });
         }
@@ -276,19 +308,29 @@ impl Ship {
         ];
 
         for ((x1, y1), (x2, y2)) in overhang_lines {
-            let (rx1, ry1) = physics::rotate_point(x1, y1, self.angle);
-            let (rx2, ry2) = physics::rotate_point(x2, y2, self.angle);
+            let (rx1, ry1) = physics::rotate_point(x1, y1, angle);
+            let (rx2, ry2) = physics::rotate_point(x2, y2, angle);
 
             vertices.push(Vertex {
-                position: [self.x + rx1, self.y + ry1],
+                position: [x + rx1, y + ry1],
                 color,
             });
             vertices.push(Vertex {
-                position: [self.x + rx2, self.y + ry2],
+                position: [x + rx2, y + ry2],
                 color,
             });
         }
 
+        vertices
+    }
+
+    pub fn get_vertices(&self) -> Vec<Vertex> {
+        let size = 0.024; // Reduced by 20% (0.03 * 0.8 = 0.024)
+        let wing_height = size * 1.5;
+        let color = [self.color.r, self.color.g, self.color.b, self.color.a];
+
+        let mut vertices = Self::hull_vertices(self.x, self.y, self.angle, 1.0, self.color);
+
         // Add thrust flame from the back if thrusting
         if self.thrust_level > 0 {
             let flame_size = match self.thrust_level {
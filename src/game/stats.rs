@@ -0,0 +1,161 @@
+// Play statistics, kept for the current session and accumulated over the lifetime of
+// the screensaver (lifetime totals are saved to disk by the config module)
+
+use super::SaucerSize;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DeathCause {
+    Asteroid,
+    Saucer,       // Collided with a saucer
+    SaucerBullet, // Shot by a saucer
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stats {
+    pub shots_fired: u64,
+    pub shots_hit: u64,
+    pub asteroids_destroyed: [u64; 3], // By size level: small, medium, large
+    pub large_saucers_destroyed: u64,
+    pub small_saucers_destroyed: u64,
+    pub deaths_by_asteroid: u64,
+    pub deaths_by_saucer: u64,
+    pub deaths_by_saucer_bullet: u64,
+    pub longest_life: f32, // Seconds
+    pub best_score: u64,
+    pub runtime: f64,      // Seconds played
+    pub current_life: f32, // Seconds since the last death (not saved)
+}
+
+impl Stats {
+    pub const FIELD_COUNT: usize = 13;
+
+    pub const FIELD_NAMES: [&'static str; Self::FIELD_COUNT] = [
+        "shots_fired",
+        "shots_hit",
+        "small_asteroids_destroyed",
+        "medium_asteroids_destroyed",
+        "large_asteroids_destroyed",
+        "large_saucers_destroyed",
+        "small_saucers_destroyed",
+        "deaths_by_asteroid",
+        "deaths_by_saucer",
+        "deaths_by_saucer_bullet",
+        "longest_life",
+        "best_score",
+        "runtime",
+    ];
+
+    pub fn update(&mut self, delta_time: f32, score: u32) {
+        self.runtime += delta_time as f64;
+        self.current_life += delta_time;
+        self.longest_life = self.longest_life.max(self.current_life);
+        self.best_score = self.best_score.max(score as u64);
+    }
+
+    pub fn record_asteroid(&mut self, size_level: i32) {
+        if let Some(count) = self.asteroids_destroyed.get_mut((size_level - 1) as usize) {
+            *count += 1;
+        }
+    }
+
+    pub fn record_saucer(&mut self, size: SaucerSize) {
+        match size {
+            SaucerSize::Large => self.large_saucers_destroyed += 1,
+            SaucerSize::Small => self.small_saucers_destroyed += 1,
+        }
+    }
+
+    pub fn record_death(&mut self, cause: DeathCause) {
+        match cause {
+            DeathCause::Asteroid => self.deaths_by_asteroid += 1,
+            DeathCause::Saucer => self.deaths_by_saucer += 1,
+            DeathCause::SaucerBullet => self.deaths_by_saucer_bullet += 1,
+        }
+        self.current_life = 0.0;
+    }
+
+    // Fraction of shots that hit something
+    pub fn accuracy(&self) -> f32 {
+        if self.shots_fired > 0 {
+            self.shots_hit as f32 / self.shots_fired as f32
+        } else {
+            0.0
+        }
+    }
+
+    pub fn deaths(&self) -> u64 {
+        self.deaths_by_asteroid + self.deaths_by_saucer + self.deaths_by_saucer_bullet
+    }
+
+    // Totals of both; records (longest life, best score) take the better of the two
+    pub fn combined(&self, other: &Stats) -> Stats {
+        let mut asteroids_destroyed = self.asteroids_destroyed;
+        for (total, count) in asteroids_destroyed
+            .iter_mut()
+            .zip(other.asteroids_destroyed)
+        {
+            *total += count;
+        }
+
+        Stats {
+            shots_fired: self.shots_fired + other.shots_fired,
+            shots_hit: self.shots_hit + other.shots_hit,
+            asteroids_destroyed,
+            large_saucers_destroyed: self.large_saucers_destroyed + other.large_saucers_destroyed,
+            small_saucers_destroyed: self.small_saucers_destroyed + other.small_saucers_destroyed,
+            deaths_by_asteroid: self.deaths_by_asteroid + other.deaths_by_asteroid,
+            deaths_by_saucer: self.deaths_by_saucer + other.deaths_by_saucer,
+            deaths_by_saucer_bullet: self.deaths_by_saucer_bullet + other.deaths_by_saucer_bullet,
+            longest_life: self.longest_life.max(other.longest_life),
+            best_score: self.best_score.max(other.best_score),
+            runtime: self.runtime + other.runtime,
+            current_life: other.current_life,
+        }
+    }
+
+    // Saved fields with their values, in FIELD_NAMES order
+    pub fn values(&self) -> [(&'static str, f64); Self::FIELD_COUNT] {
+        let values = [
+            self.shots_fired as f64,
+            self.shots_hit as f64,
+            self.asteroids_destroyed[0] as f64,
+            self.asteroids_destroyed[1] as f64,
+            self.asteroids_destroyed[2] as f64,
+            self.large_saucers_destroyed as f64,
+            self.small_saucers_destroyed as f64,
+            self.deaths_by_asteroid as f64,
+            self.deaths_by_saucer as f64,
+            self.deaths_by_saucer_bullet as f64,
+            self.longest_life as f64,
+            self.best_score as f64,
+            self.runtime,
+        ];
+        std::array::from_fn(|i| (Self::FIELD_NAMES[i], values[i]))
+    }
+
+    // Set one saved field by name; returns false for unknown names or negative values
+    pub fn set_value(&mut self, name: &str, value: f64) -> bool {
+        if value < 0.0 {
+            return false;
+        }
+
+        let count = value as u64;
+        match name {
+            "shots_fired" => self.shots_fired = count,
+            "shots_hit" => self.shots_hit = count,
+            "small_asteroids_destroyed" => self.asteroids_destroyed[0] = count,
+            "medium_asteroids_destroyed" => self.asteroids_destroyed[1] = count,
+            "large_asteroids_destroyed" => self.asteroids_destroyed[2] = count,
+            "large_saucers_destroyed" => self.large_saucers_destroyed = count,
+            "small_saucers_destroyed" => self.small_saucers_destroyed = count,
+            "deaths_by_asteroid" => self.deaths_by_asteroid = count,
+            "deaths_by_saucer" => self.deaths_by_saucer = count,
+            "deaths_by_saucer_bullet" => self.deaths_by_saucer_bullet = count,
+            "longest_life" => self.longest_life = value as f32,
+            "best_score" => self.best_score = count,
+            "runtime" => self.runtime = value,
+            _ => return false,
+        }
+        true
+    }
+}
@@ -0,0 +1,120 @@
+// Low resolution occupancy grids of the game objects, rasterized on the CPU from the
+// same line geometry the renderer draws. Meant for pixel-based agents and for quick
+// visual debugging without a GPU.
+
+use crate::game::physics::{WORLD_HALF_SIZE, WORLD_SIZE};
+use crate::game::GameState;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GridFrame {
+    World,       // Fixed to the world, centred on the origin
+    ShipCentred, // Follows the player ship, which always sits in the middle cell
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GridSettings {
+    pub width: usize,
+    pub height: usize,
+    pub frame: GridFrame,
+    pub view_size: f32, // World units across the grid (WORLD_SIZE shows the whole wrapping world)
+}
+
+impl Default for GridSettings {
+    fn default() -> Self {
+        Self {
+            width: 84,
+            height: 84,
+            frame: GridFrame::ShipCentred,
+            view_size: WORLD_SIZE,
+        }
+    }
+}
+
+// Cell intensities from 0.0 (empty) to 1.0, row-major with the top row first
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<f32>,
+}
+
+impl Grid {
+    pub fn get(&self, x: usize, y: usize) -> f32 {
+        self.cells[y * self.width + x]
+    }
+
+    // Mark a cell given in fractional grid coordinates; off-grid points are ignored
+    fn mark(&mut self, x: f32, y: f32, intensity: f32) {
+        if x < 0.0 || y < 0.0 || x >= self.width as f32 || y >= self.height as f32 {
+            return;
+        }
+        let cell = &mut self.cells[y as usize * self.width + x as usize];
+        *cell = cell.max(intensity);
+    }
+}
+
+pub fn occupancy_grid(game: &GameState, settings: &GridSettings) -> Grid {
+    let mut grid = Grid {
+        width: settings.width,
+        height: settings.height,
+        cells: vec![0.0; settings.width * settings.height],
+    };
+    if settings.width == 0 || settings.height == 0 || settings.view_size <= 0.0 {
+        return grid;
+    }
+
+    let (centre_x, centre_y) = match settings.frame {
+        GridFrame::World => (0.0, 0.0),
+        GridFrame::ShipCentred => (game.player_ship.x, game.player_ship.y),
+    };
+
+    let scale_x = settings.width as f32 / settings.view_size;
+    let scale_y = settings.height as f32 / settings.view_size;
+    let half_view = settings.view_size * 0.5;
+
+    for line in game.get_vertices().chunks_exact(2) {
+        let [x0, y0] = line[0].position;
+        let [x1, y1] = line[1].position;
+        let intensity = line[0].color[3].clamp(0.0, 1.0);
+
+        // Move the line's start into the wrapped world around the centre; the end
+        // follows it so lines never get torn apart at the wrap edge
+        let start_x = wrap(x0 - centre_x);
+        let start_y = wrap(y0 - centre_y);
+        let (dx, dy) = (x1 - x0, y1 - y0);
+
+        // Draw every wrapped copy that can overlap the view
+        for offset_x in [-WORLD_SIZE, 0.0, WORLD_SIZE] {
+            for offset_y in [-WORLD_SIZE, 0.0, WORLD_SIZE] {
+                let ax = start_x + offset_x;
+                let ay = start_y + offset_y;
+                let outside_x = ax.max(ax + dx) < -half_view || ax.min(ax + dx) > half_view;
+                let outside_y = ay.max(ay + dy) < -half_view || ay.min(ay + dy) > half_view;
+                if outside_x || outside_y {
+                    continue;
+                }
+
+                // Grid rows run downwards, world y runs upwards
+                let gx0 = (ax + half_view) * scale_x;
+                let gy0 = (half_view - ay) * scale_y;
+                let gx1 = (ax + dx + half_view) * scale_x;
+                let gy1 = (half_view - ay - dy) * scale_y;
+
+                // Sample at least twice per cell so no cell along the line is skipped
+                let steps = ((gx1 - gx0).abs().max((gy1 - gy0).abs()) * 2.0)
+                    .ceil()
+                    .max(1.0);
+                for step in 0..=steps as u32 {
+                    let t = step as f32 / steps;
+                    grid.mark(gx0 + (gx1 - gx0) * t, gy0 + (gy1 - gy0) * t, intensity);
+                }
+            }
+        }
+    }
+
+    grid
+}
+
+// Wrap a coordinate into the world range -WORLD_HALF_SIZE..WORLD_HALF_SIZE
+fn wrap(value: f32) -> f32 {
+    (value + WORLD_HALF_SIZE).rem_euclid(WORLD_SIZE) - WORLD_HALF_SIZE
+}
@@ -0,0 +1,53 @@
+// Time and date text for the clock widget
+
+use chrono::{Datelike, NaiveDateTime, Timelike};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClockFormat {
+    pub hour_24: bool, // 13:05 rather than 1:05 PM
+    pub seconds: bool,
+    pub date: bool, // Second line such as "SAT 18 OCT 2026"
+}
+
+impl Default for ClockFormat {
+    fn default() -> Self {
+        Self {
+            hour_24: true,
+            seconds: false,
+            date: false,
+        }
+    }
+}
+
+const DAYS: [&str; 7] = ["MON", "TUE", "WED", "THU", "FRI", "SAT", "SUN"];
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+// "13:05", "1:05:09 PM" and so on
+pub fn time_text(time: &NaiveDateTime, format: &ClockFormat) -> String {
+    let mut text = if format.hour_24 {
+        format!("{:02}:{:02}", time.hour(), time.minute())
+    } else {
+        let (_, hour) = time.hour12();
+        format!("{}:{:02}", hour, time.minute())
+    };
+    if format.seconds {
+        text.push_str(&format!(":{:02}", time.second()));
+    }
+    if !format.hour_24 {
+        text.push_str(if time.hour() < 12 { " AM" } else { " PM" });
+    }
+    text
+}
+
+// "SAT 18 OCT 2026"
+pub fn date_text(time: &NaiveDateTime) -> String {
+    format!(
+        "{} {} {} {}",
+        DAYS[time.weekday().num_days_from_monday() as usize],
+        time.day(),
+        MONTHS[time.month0() as usize],
+        time.year()
+    )
+}
@@ -0,0 +1,232 @@
+// Debug overlay, toggled with F3 or `debug = true`: collision shapes, velocities, what
+// the AI is aiming at and how close it thinks danger is, the wrap margin, and entity
+// counts and timings. Drawn in world coordinates on top of the game.
+
+use crate::game::{physics, AiMode, GameState};
+use crate::renderer::layout::draw_text;
+use crate::renderer::{Color, TextStyle, Vertex};
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+const CIRCLE_SEGMENTS: usize = 24;
+const VELOCITY_SECONDS: f32 = 0.5; // Velocity vectors show where things will be this soon
+const SHIP_BULLET_RADIUS: f32 = 0.1; // Matches the bullet-player collision check
+const TEXT_SIZE: f32 = 0.03;
+
+pub fn overlay_vertices(game_state: &GameState, frame_vertex_count: usize) -> Vec<Vertex> {
+    let collision = Color {
+        a: 0.6,
+        ..Color::CYAN
+    }
+    .to_array();
+    let velocity = Color::YELLOW.to_array();
+    let mut vertices = Vec::new();
+
+    // Collision circles and velocity vectors
+    for asteroid in game_state.asteroids.iter().filter(|a| a.alive) {
+        let position = (asteroid.x, asteroid.y);
+        push_circle(&mut vertices, position, asteroid.size, collision);
+        push_velocity(
+            &mut vertices,
+            position,
+            (asteroid.vx, asteroid.vy),
+            velocity,
+        );
+    }
+    for saucer in game_state.saucers.iter().filter(|s| s.alive) {
+        let position = (saucer.x, saucer.y);
+        push_circle(
+            &mut vertices,
+            position,
+            saucer.get_collision_radius(),
+            collision,
+        );
+        push_velocity(&mut vertices, position, (saucer.vx, saucer.vy), velocity);
+    }
+    for bullet in game_state.bullets.iter().filter(|b| b.alive) {
+        push_velocity(
+            &mut vertices,
+            (bullet.x, bullet.y),
+            (bullet.vx, bullet.vy),
+            velocity,
+        );
+    }
+
+    let ship = &game_state.player_ship;
+    let position = (ship.x, ship.y);
+    push_circle(&mut vertices, position, SHIP_BULLET_RADIUS, collision);
+    push_velocity(&mut vertices, position, (ship.vx, ship.vy), velocity);
+
+    // The AI's view: danger and warning radii, its target and the heading it wants
+    if !game_state.is_player_controlled() {
+        let ai = &game_state.ai_state;
+        let profile = &game_state.ai_profile;
+        let evading = ai.mode == AiMode::Evading;
+        let danger = Color {
+            a: if evading { 1.0 } else { 0.5 },
+            ..Color::RED
+        };
+        let warning = Color {
+            a: 0.5,
+            ..Color::YELLOW
+        };
+        // Clearance thresholds, drawn as radii around the ship
+        push_circle(
+            &mut vertices,
+            position,
+            profile.danger_distance,
+            danger.to_array(),
+        );
+        push_circle(
+            &mut vertices,
+            position,
+            profile.warning_distance,
+            warning.to_array(),
+        );
+
+        let intent = Color::MAGENTA.to_array();
+        if let Some((x, y)) = ai.target {
+            // Across the wrap edge if that is nearer, as the AI measures it
+            let end = (
+                ship.x + physics::wrapped_delta(ship.x, x),
+                ship.y + physics::wrapped_delta(ship.y, y),
+            );
+            push_line(&mut vertices, position, end, intent);
+            push_cross(&mut vertices, end.0, end.1, 0.03, intent);
+        }
+        if let Some(angle) = ai.heading {
+            let length = profile.warning_distance;
+            let (dx, dy) = physics::rotate_point(0.0, length, angle);
+            push_line(&mut vertices, position, (ship.x + dx, ship.y + dy), intent);
+        }
+    }
+
+    // Wrap margin: the world runs past the screen edge to ±WORLD_HALF_SIZE. Objects out
+    // there are marked on the edge nearest to them.
+    let margin = Color {
+        a: 0.5,
+        ..game_state.hud_color
+    }
+    .to_array();
+    let edge = 0.99;
+    push_line(&mut vertices, (-edge, -edge), (edge, -edge), margin);
+    push_line(&mut vertices, (edge, -edge), (edge, edge), margin);
+    push_line(&mut vertices, (edge, edge), (-edge, edge), margin);
+    push_line(&mut vertices, (-edge, edge), (-edge, -edge), margin);
+    let positions = game_state
+        .asteroids
+        .iter()
+        .map(|a| (a.x, a.y))
+        .chain(game_state.saucers.iter().map(|s| (s.x, s.y)))
+        .chain(game_state.bullets.iter().map(|b| (b.x, b.y)));
+    for (x, y) in positions {
+        if x.abs() > 1.0 || y.abs() > 1.0 {
+            push_cross(
+                &mut vertices,
+                x.clamp(-edge, edge),
+                y.clamp(-edge, edge),
+                0.015,
+                margin,
+            );
+        }
+    }
+
+    vertices.extend(stats_text(game_state, frame_vertex_count));
+    vertices
+}
+
+// Entity counts, timings and the AI's state, bottom left above the level readout
+fn stats_text(game_state: &GameState, frame_vertex_count: usize) -> Vec<Vertex> {
+    let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+    let timings = &game_state.timings;
+    let fps = if timings.frame_time > 0.0 {
+        1.0 / timings.frame_time
+    } else {
+        0.0
+    };
+    let ai = &game_state.ai_state;
+    let profile = &game_state.ai_profile;
+
+    let mut lines = vec![
+        format!(
+            "ASTEROIDS {}  SAUCERS {}  BULLETS {}",
+            game_state.asteroids.len(),
+            game_state.saucers.len(),
+            game_state.bullets.len()
+        ),
+        format!("VERTICES {}", frame_vertex_count),
+        format!(
+            "FRAME {:.1} MS  {:.0} FPS",
+            timings.frame_time * 1000.0,
+            fps
+        ),
+        format!(
+            "UPDATE {:.3} MS  AI {:.3} MS  COLLISIONS {:.3} MS",
+            ms(timings.update),
+            ms(timings.ai),
+            ms(timings.collisions)
+        ),
+        format!(
+            "DANGER {:.2}  WARNING {:.2}  WRAP {:.1}",
+            profile.danger_distance,
+            profile.warning_distance,
+            physics::WORLD_HALF_SIZE
+        ),
+    ];
+    if game_state.is_player_controlled() {
+        lines.push("AI OFF: PLAYER".to_string());
+    } else if ai.clearance.is_finite() {
+        lines.push(format!(
+            "AI {}  CLEARANCE {:.2}",
+            ai.mode.name(),
+            ai.clearance
+        ));
+    } else {
+        lines.push(format!("AI {}", ai.mode.name()));
+    }
+
+    let style = TextStyle::label(TEXT_SIZE, game_state.hud_color);
+    let top = -0.55;
+    let mut vertices = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let y = top - i as f32 * TEXT_SIZE * 1.6;
+        vertices.extend(draw_text(line, -0.95, y, &style));
+    }
+    vertices
+}
+
+fn push_line(vertices: &mut Vec<Vertex>, from: (f32, f32), to: (f32, f32), color: [f32; 4]) {
+    vertices.push(Vertex {
+        position: [from.0, from.1],
+        color,
+    });
+    vertices.push(Vertex {
+        position: [to.0, to.1],
+        color,
+    });
+}
+
+fn push_circle(vertices: &mut Vec<Vertex>, (x, y): (f32, f32), radius: f32, color: [f32; 4]) {
+    let point = |i: usize| {
+        let angle = i as f32 / CIRCLE_SEGMENTS as f32 * TAU;
+        (x + angle.cos() * radius, y + angle.sin() * radius)
+    };
+    for i in 0..CIRCLE_SEGMENTS {
+        push_line(vertices, point(i), point(i + 1), color);
+    }
+}
+
+fn push_velocity(
+    vertices: &mut Vec<Vertex>,
+    (x, y): (f32, f32),
+    (vx, vy): (f32, f32),
+    color: [f32; 4],
+) {
+    let end = (x + vx * VELOCITY_SECONDS, y + vy * VELOCITY_SECONDS);
+    push_line(vertices, (x, y), end, color);
+}
+
+fn push_cross(vertices: &mut Vec<Vertex>, x: f32, y: f32, size: f32, color: [f32; 4]) {
+    push_line(vertices, (x - size, y - size), (x + size, y + size), color);
+    push_line(vertices, (x - size, y + size), (x + size, y - size), color);
+}
@@ -0,0 +1,481 @@
+// Heads-up display, kept apart from the simulation. The HUD is a list of elements, each a
+// widget placed relative to a screen anchor, drawn from a HudView snapshot of the game.
+// Instruments can be moved, hidden or added by editing the layout alone.
+
+mod clock;
+mod debug;
+mod widgets;
+
+pub use clock::ClockFormat;
+pub use widgets::{Icon, Widget};
+
+use crate::game::{GameState, SaucerSize, Stats};
+use crate::renderer::layout::line_width;
+use crate::renderer::{Align, Color, Frame, Layer, NumberFormat, TextStyle, Vertex};
+use chrono::{Local, NaiveDateTime};
+
+// A game object as seen by the radar
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BlipKind {
+    Asteroid(i32), // Size level: 3 large, 2 medium, 1 small
+    LargeSaucer,
+    SmallSaucer,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Blip {
+    pub x: f32,
+    pub y: f32,
+    pub kind: BlipKind,
+}
+
+// What the HUD shows, copied out of the game state each frame
+#[derive(Clone, Debug)]
+pub struct HudView {
+    pub score: u32,
+    pub lives: u32,
+    pub wave: u32,
+    pub ship_x: f32,
+    pub ship_y: f32,
+    pub heading: f32, // Ship heading in degrees, 0-360, 0 = up
+    pub speed: f32,
+    pub energy: f32,     // 0.0 to 1.0
+    pub difficulty: f32, // 0.0 to 1.0
+    pub session: Stats,
+    pub lifetime: Stats, // Including this session
+    pub show_debug: bool,
+    pub blips: Vec<Blip>, // Live asteroids and saucers, in world coordinates
+    pub local_time: NaiveDateTime,
+    pub aspect_ratio: f32,
+    pub color: Color,
+}
+
+impl HudView {
+    pub fn new(game_state: &GameState) -> Self {
+        let ship = &game_state.player_ship;
+
+        let asteroids = game_state
+            .asteroids
+            .iter()
+            .filter(|a| a.alive)
+            .map(|a| Blip {
+                x: a.x,
+                y: a.y,
+                kind: BlipKind::Asteroid(a.size_level),
+            });
+        let saucers = game_state.saucers.iter().filter(|s| s.alive).map(|s| Blip {
+            x: s.x,
+            y: s.y,
+            kind: match s.size {
+                SaucerSize::Large => BlipKind::LargeSaucer,
+                SaucerSize::Small => BlipKind::SmallSaucer,
+            },
+        });
+
+        Self {
+            score: game_state.score,
+            lives: game_state.lives,
+            wave: game_state.wave,
+            ship_x: ship.x,
+            ship_y: ship.y,
+            heading: ship.angle.to_degrees().rem_euclid(360.0),
+            speed: (ship.vx * ship.vx + ship.vy * ship.vy).sqrt(),
+            energy: ship.energy,
+            difficulty: game_state.difficulty.level,
+            session: game_state.stats,
+            lifetime: game_state.lifetime_totals(),
+            show_debug: game_state.show_debug,
+            blips: asteroids.chain(saucers).collect(),
+            local_time: Local::now().naive_local(),
+            aspect_ratio: game_state.width / game_state.height,
+            color: game_state.hud_color,
+        }
+    }
+
+    pub fn value(&self, value: Value) -> f64 {
+        match value {
+            Value::Score => self.score as f64,
+            Value::Lives => self.lives as f64,
+            Value::Wave => self.wave as f64,
+            Value::Heading => self.heading as f64,
+            Value::Speed => self.speed as f64,
+            Value::Energy => self.energy as f64,
+            Value::Difficulty => self.difficulty as f64,
+        }
+    }
+}
+
+// Quantities a widget can display
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Value {
+    Score,
+    Lives,
+    Wave,
+    Heading,
+    Speed,
+    Energy,
+    Difficulty,
+}
+
+// Screen point an element is positioned from, in normalized device coordinates
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    pub fn from_name(name: &str) -> Option<Anchor> {
+        match name.to_ascii_lowercase().replace('-', "_").as_str() {
+            "top_left" => Some(Anchor::TopLeft),
+            "top" => Some(Anchor::Top),
+            "top_right" => Some(Anchor::TopRight),
+            "left" => Some(Anchor::Left),
+            "center" | "centre" => Some(Anchor::Center),
+            "right" => Some(Anchor::Right),
+            "bottom_left" => Some(Anchor::BottomLeft),
+            "bottom" => Some(Anchor::Bottom),
+            "bottom_right" => Some(Anchor::BottomRight),
+            _ => None,
+        }
+    }
+
+    pub fn origin(self) -> (f32, f32) {
+        match self {
+            Anchor::TopLeft => (-1.0, 1.0),
+            Anchor::Top => (0.0, 1.0),
+            Anchor::TopRight => (1.0, 1.0),
+            Anchor::Left => (-1.0, 0.0),
+            Anchor::Center => (0.0, 0.0),
+            Anchor::Right => (1.0, 0.0),
+            Anchor::BottomLeft => (-1.0, -1.0),
+            Anchor::Bottom => (0.0, -1.0),
+            Anchor::BottomRight => (1.0, -1.0),
+        }
+    }
+}
+
+pub struct Element {
+    pub name: String, // Elements of one instrument share a name, so they hide together
+    pub anchor: Anchor,
+    pub offset: (f32, f32), // From the anchor to the widget's origin
+    pub widget: Widget,
+    pub visible: bool,
+    pub debug_only: bool, // Only drawn while the debug overlay is on
+}
+
+impl Element {
+    pub fn new(name: &str, anchor: Anchor, offset: (f32, f32), widget: Widget) -> Self {
+        Self {
+            name: name.to_string(),
+            anchor,
+            offset,
+            widget,
+            visible: true,
+            debug_only: false,
+        }
+    }
+
+    pub fn debug_only(mut self) -> Self {
+        self.debug_only = true;
+        self
+    }
+
+    pub fn position(&self) -> (f32, f32) {
+        let (x, y) = self.anchor.origin();
+        (x + self.offset.0, y + self.offset.1)
+    }
+}
+
+pub struct Hud {
+    pub elements: Vec<Element>,
+}
+
+impl Default for Hud {
+    // The classic layout: score and stats top-left, instrument cluster top-right
+    fn default() -> Self {
+        let label_size = 0.025;
+        let value_size = 0.03;
+        let cluster_x = -0.45; // Labels, from the right edge
+        let gauge_x = -0.27; // Gauges start here
+        let value_x = -0.035; // Numeric values end here
+        let row_y = |row: usize| -0.1 - row as f32 * 0.12;
+
+        let label = |text: &str| Widget::Label {
+            text: text.to_string(),
+            size: label_size,
+            align: Align::Left,
+        };
+        let value = |value, scale, max, format| Widget::Readout {
+            value,
+            scale,
+            max,
+            format,
+            size: value_size,
+            align: Align::Right,
+        };
+
+        let elements = vec![
+            Element::new(
+                "score",
+                Anchor::TopLeft,
+                (0.05, -0.1),
+                Widget::Readout {
+                    value: Value::Score,
+                    scale: 1.0,
+                    max: None,
+                    format: NumberFormat::default(),
+                    size: 0.06,
+                    align: Align::Left,
+                },
+            ),
+            // Spare ships under the score, wave number beside it
+            Element::new(
+                "lives",
+                Anchor::TopLeft,
+                (0.06, -0.145),
+                Widget::IconRow {
+                    icon: Icon::Ship,
+                    count: Value::Lives,
+                    size: 0.5,
+                    spacing: 0.03,
+                },
+            ),
+            Element::new("wave", Anchor::TopLeft, (0.45, -0.1), label("WAVE")),
+            Element::new(
+                "wave",
+                Anchor::TopLeft,
+                (0.53, -0.1),
+                Widget::Readout {
+                    value: Value::Wave,
+                    scale: 1.0,
+                    max: None,
+                    format: NumberFormat::default(),
+                    size: value_size,
+                    align: Align::Left,
+                },
+            ),
+            Element::new(
+                "stats",
+                Anchor::TopLeft,
+                (0.05, -0.22),
+                Widget::StatsPage {
+                    size: 0.025,
+                    row_spacing: 0.045,
+                    width: 0.5,
+                },
+            ),
+            // Direction: compass plus a three-digit heading
+            Element::new(
+                "direct",
+                Anchor::TopRight,
+                (cluster_x, row_y(0)),
+                label("DIRECT"),
+            ),
+            Element::new(
+                "direct",
+                Anchor::TopRight,
+                (gauge_x + 0.06, row_y(0) - 0.0125),
+                Widget::Compass {
+                    value: Value::Heading,
+                    width: 0.12,
+                },
+            ),
+            Element::new(
+                "direct",
+                Anchor::TopRight,
+                (value_x, row_y(0)),
+                value(Value::Heading, 1.0, None, NumberFormat::padded(3)),
+            ),
+            // Thrust: speed bar and a 0-9 reading
+            Element::new(
+                "thrust",
+                Anchor::TopRight,
+                (cluster_x, row_y(1)),
+                label("THRUST"),
+            ),
+            Element::new(
+                "thrust",
+                Anchor::TopRight,
+                (gauge_x, row_y(1)),
+                Widget::BarGauge {
+                    value: Value::Speed,
+                    full_scale: 1.0,
+                    width: 0.12,
+                    height: 0.025,
+                    lines: 3,
+                },
+            ),
+            Element::new(
+                "thrust",
+                Anchor::TopRight,
+                (value_x, row_y(1)),
+                value(Value::Speed, 10.0, Some(9.0), NumberFormat::default()),
+            ),
+            // Power: battery and percentage
+            Element::new(
+                "power",
+                Anchor::TopRight,
+                (cluster_x, row_y(2)),
+                label("POWER"),
+            ),
+            Element::new(
+                "power",
+                Anchor::TopRight,
+                (gauge_x, row_y(2)),
+                Widget::Battery {
+                    value: Value::Energy,
+                    width: 0.12,
+                    height: 0.025,
+                },
+            ),
+            Element::new(
+                "power",
+                Anchor::TopRight,
+                (value_x, row_y(2)),
+                value(Value::Energy, 100.0, None, NumberFormat::default()),
+            ),
+            // Radar: the whole wrapped world, including the off-screen margin
+            Element::new(
+                "radar",
+                Anchor::TopRight,
+                (cluster_x, row_y(3)),
+                label("RADAR"),
+            ),
+            Element::new(
+                "radar",
+                Anchor::TopRight,
+                (gauge_x, row_y(3)),
+                Widget::Radar {
+                    width: 0.2,
+                    ship_centred: false,
+                    sweep_period: Some(3.0),
+                },
+            ),
+            // Difficulty level (0-100) in the bottom-left corner
+            Element::new("level", Anchor::BottomLeft, (0.05, 0.07), label("LEVEL")).debug_only(),
+            Element::new(
+                "level",
+                Anchor::BottomLeft,
+                (0.18, 0.07),
+                Widget::Readout {
+                    value: Value::Difficulty,
+                    scale: 100.0,
+                    max: None,
+                    format: NumberFormat::default(),
+                    size: value_size,
+                    align: Align::Left,
+                },
+            )
+            .debug_only(),
+        ];
+
+        Self { elements }
+    }
+}
+
+impl Hud {
+    // Show or hide every element with the given name; false if there is none
+    pub fn set_visible(&mut self, name: &str, visible: bool) -> bool {
+        let mut found = false;
+        for element in self.elements.iter_mut().filter(|e| e.name == name) {
+            element.visible = visible;
+            found = true;
+        }
+        found
+    }
+
+    // The HUD, the game drawn over it, and the debug overlay over both when it is on
+    pub fn draw_frame(&self, game_state: &GameState, frame: &mut Frame) {
+        frame.push_vertices(Layer::Hud, &self.get_vertices(&HudView::new(game_state)));
+        frame.push_vertices(Layer::Game, &game_state.get_vertices());
+        if game_state.show_debug {
+            let count = frame.segments.len() * 2;
+            frame.push_vertices(Layer::Overlay, &debug::overlay_vertices(game_state, count));
+        }
+    }
+
+    // The same as one line list
+    pub fn frame_vertices(&self, game_state: &GameState) -> Vec<Vertex> {
+        let mut frame = Frame::default();
+        self.draw_frame(game_state, &mut frame);
+        frame.vertices()
+    }
+
+    // Add a clock at a screen anchor, inset from the edge and aligned away from it. A ghost
+    // clock adds large, faint digits in the middle of the screen, behind the game.
+    pub fn add_clock(&mut self, format: ClockFormat, anchor: Anchor, size: f32, ghost: bool) {
+        const INSET: f32 = 0.05;
+        let (anchor_x, anchor_y) = anchor.origin();
+        let align = match anchor_x {
+            x if x < 0.0 => Align::Left,
+            x if x > 0.0 => Align::Right,
+            _ => Align::Center,
+        };
+        let cap_height = size * 0.6;
+        let date_height = if format.date { size * 0.9 } else { 0.0 };
+        let offset = (
+            -anchor_x * INSET,
+            match anchor_y {
+                y if y > 0.0 => -(INSET + cap_height),
+                y if y < 0.0 => INSET + date_height,
+                _ => -cap_height * 0.5,
+            },
+        );
+
+        if ghost {
+            // As large as fits across most of the screen, drawn first so everything else is on top
+            let format = ClockFormat {
+                date: false,
+                ..format
+            };
+            let widest = clock::time_text(&NaiveDateTime::default(), &format).replace('0', "8");
+            let width = line_width(&widest, &TextStyle::label(1.0, Color::WHITE));
+            let ghost_size = (1.6 / width).min(0.5);
+            self.elements.insert(
+                0,
+                Element::new(
+                    "clock",
+                    Anchor::Center,
+                    (0.0, -ghost_size * 0.3),
+                    Widget::Clock {
+                        format,
+                        size: ghost_size,
+                        align: Align::Center,
+                        alpha: 0.12,
+                    },
+                ),
+            );
+        }
+        self.elements.push(Element::new(
+            "clock",
+            anchor,
+            offset,
+            Widget::Clock {
+                format,
+                size,
+                align,
+                alpha: 1.0,
+            },
+        ));
+    }
+
+    pub fn get_vertices(&self, view: &HudView) -> Vec<Vertex> {
+        let mut vertices = Vec::new();
+        for element in &self.elements {
+            if !element.visible || (element.debug_only && !view.show_debug) {
+                continue;
+            }
+            let (x, y) = element.position();
+            vertices.extend(element.widget.draw(x, y, view));
+        }
+        vertices
+    }
+}
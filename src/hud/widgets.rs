@@ -0,0 +1,569 @@
+use super::clock::{self, ClockFormat};
+use super::{BlipKind, HudView, Value};
+use crate::game::physics::{self, WORLD_HALF_SIZE};
+use crate::game::Ship;
+use crate::renderer::layout::{draw_number, draw_text};
+use crate::renderer::{font, Align, NumberFormat, TextStyle, Vertex};
+use std::f32::consts::{PI, TAU};
+
+// HUD building blocks. Each is drawn from an origin point given by its element:
+// text and readouts from their baseline, gauges from their top-left corner and the
+// compass from its centre.
+#[derive(Clone, Debug)]
+pub enum Widget {
+    Label {
+        text: String,
+        size: f32,
+        align: Align,
+    },
+    // A number, shown as floor(value * scale), capped at `max`
+    Readout {
+        value: Value,
+        scale: f64,
+        max: Option<f64>,
+        format: NumberFormat,
+        size: f32,
+        align: Align,
+    },
+    // Outlined bar filled with `lines` horizontal strokes, full at `full_scale`
+    BarGauge {
+        value: Value,
+        full_scale: f32,
+        width: f32,
+        height: f32,
+        lines: usize,
+    },
+    // Bar gauge with a terminal tip, for values from 0 to 1
+    Battery {
+        value: Value,
+        width: f32,
+        height: f32,
+    },
+    // Screen-shaped frame with a dial and a cursor pointing along a heading in degrees
+    Compass {
+        value: Value,
+        width: f32,
+    },
+    // An icon repeated `count` times, `spacing` apart, starting at the origin
+    IconRow {
+        icon: Icon,
+        count: Value,
+        size: f32, // Cap height for glyphs, scale of the ship
+        spacing: f32,
+    },
+    // Square map of the whole wrapped world, `width` wide, with the visible screen
+    // outlined. Centred on the ship, or fixed with the ship at its world position.
+    Radar {
+        width: f32,
+        ship_centred: bool,
+        sweep_period: Option<f32>, // Seconds per turn of the sweep line, or None for no sweep
+    },
+    // Local time in the stroke font, with the date underneath at half size
+    Clock {
+        format: ClockFormat,
+        size: f32,
+        align: Align,
+        alpha: f32, // Opacity, low for ghost digits behind the game
+    },
+    // Session and lifetime statistics, alternating pages
+    StatsPage {
+        size: f32,
+        row_spacing: f32,
+        width: f32, // Labels start at the origin, values end this far right of it
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Icon {
+    Glyph(char), // A stroke font character, baseline at the origin
+    Ship,        // The player ship, nose up, centred on the origin
+}
+
+impl Widget {
+    pub fn draw(&self, x: f32, y: f32, view: &HudView) -> Vec<Vertex> {
+        match self {
+            Widget::Label { text, size, align } => {
+                let style = TextStyle {
+                    align: *align,
+                    ..TextStyle::label(*size, view.color)
+                };
+                draw_text(text, x, y, &style)
+            }
+            Widget::Readout {
+                value,
+                scale,
+                max,
+                format,
+                size,
+                align,
+            } => {
+                let mut number = view.value(*value) * scale;
+                if let Some(max) = max {
+                    number = number.min(*max);
+                }
+                let style = TextStyle {
+                    align: *align,
+                    ..TextStyle::number(*size, view.color)
+                };
+                draw_number(number.floor(), format, x, y, &style)
+            }
+            Widget::BarGauge {
+                value,
+                full_scale,
+                width,
+                height,
+                lines,
+            } => {
+                let fraction = view.value(*value) as f32 / full_scale;
+                bar_gauge(x, y, *width, *height, *lines, fraction, view)
+            }
+            Widget::Battery {
+                value,
+                width,
+                height,
+            } => battery(x, y, *width, *height, view.value(*value) as f32, view),
+            Widget::Compass { value, width } => {
+                compass(x, y, *width, view.value(*value) as f32, view)
+            }
+            Widget::IconRow {
+                icon,
+                count,
+                size,
+                spacing,
+            } => icon_row(
+                x,
+                y,
+                *icon,
+                view.value(*count) as usize,
+                *size,
+                *spacing,
+                view,
+            ),
+            Widget::Radar {
+                width,
+                ship_centred,
+                sweep_period,
+            } => radar(x, y, *width, *ship_centred, *sweep_period, view),
+            Widget::Clock {
+                format,
+                size,
+                align,
+                alpha,
+            } => clock(x, y, format, *size, *align, *alpha, view),
+            Widget::StatsPage {
+                size,
+                row_spacing,
+                width,
+            } => stats_page(x, y, *size, *row_spacing, *width, view),
+        }
+    }
+}
+
+fn push_line(vertices: &mut Vec<Vertex>, from: (f32, f32), to: (f32, f32), color: [f32; 4]) {
+    vertices.push(Vertex {
+        position: [from.0, from.1],
+        color,
+    });
+    vertices.push(Vertex {
+        position: [to.0, to.1],
+        color,
+    });
+}
+
+fn push_rect(
+    vertices: &mut Vec<Vertex>,
+    left: f32,
+    top: f32,
+    width: f32,
+    height: f32,
+    color: [f32; 4],
+) {
+    let (right, bottom) = (left + width, top - height);
+    push_line(vertices, (left, top), (right, top), color);
+    push_line(vertices, (right, top), (right, bottom), color);
+    push_line(vertices, (right, bottom), (left, bottom), color);
+    push_line(vertices, (left, bottom), (left, top), color);
+}
+
+// Horizontal fill strokes inside a gauge outline
+fn push_fill(
+    vertices: &mut Vec<Vertex>,
+    left: f32,
+    top: f32,
+    fill: f32,
+    height: f32,
+    lines: usize,
+    color: [f32; 4],
+) {
+    if fill <= 0.0 || lines == 0 {
+        return;
+    }
+    for i in 0..lines {
+        let t = if lines > 1 {
+            i as f32 / (lines - 1) as f32
+        } else {
+            0.5
+        };
+        let y = top - 0.005 - (height - 0.01) * t;
+        push_line(vertices, (left + 0.003, y), (left + 0.003 + fill, y), color);
+    }
+}
+
+fn bar_gauge(
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    lines: usize,
+    fraction: f32,
+    view: &HudView,
+) -> Vec<Vertex> {
+    let color = view.color.to_array();
+    let mut vertices = Vec::new();
+
+    push_rect(&mut vertices, x, y, width, height, color);
+    let fill = (width - 0.005) * fraction.clamp(0.0, 1.0);
+    push_fill(&mut vertices, x, y, fill, height, lines, color);
+
+    vertices
+}
+
+fn battery(x: f32, y: f32, width: f32, height: f32, charge: f32, view: &HudView) -> Vec<Vertex> {
+    let color = view.color.to_array();
+    let mut vertices = Vec::new();
+
+    push_rect(&mut vertices, x, y, width, height, color);
+
+    // Battery tip on the right end
+    let tip_width = 0.008;
+    let tip_height = height * 0.4;
+    let tip_center = y - height / 2.0;
+    let right = x + width;
+    push_line(
+        &mut vertices,
+        (right, tip_center + tip_height),
+        (right + tip_width, tip_center + tip_height),
+        color,
+    );
+    push_line(
+        &mut vertices,
+        (right + tip_width, tip_center + tip_height),
+        (right + tip_width, tip_center - tip_height),
+        color,
+    );
+    push_line(
+        &mut vertices,
+        (right + tip_width, tip_center - tip_height),
+        (right, tip_center - tip_height),
+        color,
+    );
+
+    let fill = (width - 0.005) * charge.clamp(0.0, 1.0);
+    push_fill(&mut vertices, x, y, fill, height, 4, color);
+
+    vertices
+}
+
+fn compass(x: f32, y: f32, width: f32, heading: f32, view: &HudView) -> Vec<Vertex> {
+    let color = view.color.to_array();
+    let mut vertices = Vec::new();
+    let y_scale = view.aspect_ratio; // Keeps the dial round on wide screens
+    let radius = 0.025;
+
+    // Frame with the shape of the screen
+    let half_width = width / 2.0;
+    let half_height = half_width / view.aspect_ratio;
+    push_rect(
+        &mut vertices,
+        x - half_width,
+        y + half_height,
+        width,
+        half_height * 2.0,
+        color,
+    );
+
+    // Dial, slightly inside the cursor's reach
+    let segments = 16;
+    let dial_radius = radius * 0.8;
+    for i in 0..segments {
+        let angle1 = (i as f32 / segments as f32) * 2.0 * PI;
+        let angle2 = ((i + 1) as f32 / segments as f32) * 2.0 * PI;
+        push_line(
+            &mut vertices,
+            (
+                x + dial_radius * angle1.cos(),
+                y + (dial_radius / y_scale) * angle1.sin(),
+            ),
+            (
+                x + dial_radius * angle2.cos(),
+                y + (dial_radius / y_scale) * angle2.sin(),
+            ),
+            color,
+        );
+    }
+
+    // Centre dot, drawn as a small cross
+    let dot_size = 0.002;
+    push_line(&mut vertices, (x - dot_size, y), (x + dot_size, y), color);
+    push_line(
+        &mut vertices,
+        (x, y - dot_size / y_scale),
+        (x, y + dot_size / y_scale),
+        color,
+    );
+
+    // Triangular cursor pointing along the heading (0 degrees = up)
+    let angle = heading.to_radians() + PI / 2.0;
+    let point = |angle: f32, radius: f32| {
+        (
+            x + radius * angle.cos(),
+            y + (radius / y_scale) * angle.sin(),
+        )
+    };
+    let tip = point(angle, radius);
+    let base1 = point(angle + PI * 0.85, radius * 0.4);
+    let base2 = point(angle - PI * 0.85, radius * 0.4);
+    push_line(&mut vertices, tip, base1, color);
+    push_line(&mut vertices, base1, base2, color);
+    push_line(&mut vertices, base2, tip, color);
+
+    vertices
+}
+
+fn icon_row(
+    x: f32,
+    y: f32,
+    icon: Icon,
+    count: usize,
+    size: f32,
+    spacing: f32,
+    view: &HudView,
+) -> Vec<Vertex> {
+    (0..count)
+        .flat_map(|i| {
+            let icon_x = x + i as f32 * spacing;
+            match icon {
+                Icon::Glyph(ch) => {
+                    font::render_glyph(&font::glyph_or_missing(ch), icon_x, y, size, view.color)
+                }
+                Icon::Ship => Ship::hull_vertices(icon_x, y, 0.0, size, view.color),
+            }
+        })
+        .collect()
+}
+
+fn radar(
+    x: f32,
+    y: f32,
+    width: f32,
+    ship_centred: bool,
+    sweep_period: Option<f32>,
+    view: &HudView,
+) -> Vec<Vertex> {
+    let color = view.color.to_array();
+    let faded = |alpha: f32| [color[0], color[1], color[2], color[3] * alpha];
+    let mut vertices = Vec::new();
+
+    // Square on screen: NDC height is stretched by the aspect ratio
+    let half_width = width / 2.0;
+    let half_height = half_width * view.aspect_ratio;
+    let (center_x, center_y) = (x + half_width, y - half_height);
+    let scale_x = half_width / WORLD_HALF_SIZE;
+    let scale_y = half_height / WORLD_HALF_SIZE;
+    push_rect(&mut vertices, x, y, width, half_height * 2.0, color);
+
+    // World offsets from the radar centre: the ship, or the middle of the world
+    let offset = |wx: f32, wy: f32| {
+        if ship_centred {
+            (
+                physics::wrapped_delta(view.ship_x, wx),
+                physics::wrapped_delta(view.ship_y, wy),
+            )
+        } else {
+            (wx, wy)
+        }
+    };
+    let to_radar = |(dx, dy): (f32, f32)| (center_x + dx * scale_x, center_y + dy * scale_y);
+
+    // The visible screen, -1 to 1 in both axes; anything outside it is in the wrap margin
+    if !ship_centred {
+        push_rect(
+            &mut vertices,
+            center_x - scale_x,
+            center_y + scale_y,
+            scale_x * 2.0,
+            scale_y * 2.0,
+            faded(0.35),
+        );
+    }
+
+    // Sweep line turning clockwise; blips fade until it passes them again
+    let sweep_angle = sweep_period.map(|period| {
+        let angle = PI / 2.0 - (view.session.runtime as f32 / period.max(0.1)) * TAU;
+        push_line(
+            &mut vertices,
+            (center_x, center_y),
+            (
+                center_x + half_width * angle.cos(),
+                center_y + half_height * angle.sin(),
+            ),
+            faded(0.6),
+        );
+        angle
+    });
+    let blip_alpha = |(dx, dy): (f32, f32)| match sweep_angle {
+        Some(sweep) => 1.0 - 0.75 * (dy.atan2(dx) - sweep).rem_euclid(TAU) / TAU,
+        None => 1.0,
+    };
+
+    for blip in &view.blips {
+        let delta = offset(blip.x, blip.y);
+        let (bx, by) = to_radar(delta);
+        let blip_color = faded(blip_alpha(delta));
+        match blip.kind {
+            BlipKind::Asteroid(level) => {
+                // Diamond, larger for bigger asteroids
+                let r = 0.002 + 0.0015 * level.clamp(1, 3) as f32;
+                let ry = r * view.aspect_ratio;
+                push_line(&mut vertices, (bx, by + ry), (bx + r, by), blip_color);
+                push_line(&mut vertices, (bx + r, by), (bx, by - ry), blip_color);
+                push_line(&mut vertices, (bx, by - ry), (bx - r, by), blip_color);
+                push_line(&mut vertices, (bx - r, by), (bx, by + ry), blip_color);
+            }
+            BlipKind::LargeSaucer | BlipKind::SmallSaucer => {
+                let height = if blip.kind == BlipKind::LargeSaucer {
+                    0.014
+                } else {
+                    0.009
+                };
+                let glyph = font::glyph_or_missing(font::SAUCER_ICON);
+                let glyph_width =
+                    (glyph.advance - font::GLYPH_SPACING) * height / font::GLYPH_HEIGHT;
+                let mut saucer = font::render_glyph(
+                    &glyph,
+                    bx - glyph_width / 2.0,
+                    by - height / 3.0,
+                    height,
+                    view.color,
+                );
+                for vertex in &mut saucer {
+                    vertex.color = blip_color;
+                }
+                vertices.extend(saucer);
+            }
+        }
+    }
+
+    // The ship, pointing along its heading
+    let (sx, sy) = to_radar(offset(view.ship_x, view.ship_y));
+    let angle = view.heading.to_radians() + PI / 2.0;
+    let size = 0.008;
+    let point = |angle: f32, radius: f32| {
+        (
+            sx + radius * angle.cos(),
+            sy + radius * view.aspect_ratio * angle.sin(),
+        )
+    };
+    let tip = point(angle, size);
+    let left = point(angle + PI * 0.8, size * 0.7);
+    let right = point(angle - PI * 0.8, size * 0.7);
+    push_line(&mut vertices, tip, left, color);
+    push_line(&mut vertices, left, right, color);
+    push_line(&mut vertices, right, tip, color);
+
+    vertices
+}
+
+fn clock(
+    x: f32,
+    y: f32,
+    format: &ClockFormat,
+    size: f32,
+    align: Align,
+    alpha: f32,
+    view: &HudView,
+) -> Vec<Vertex> {
+    let mut color = view.color;
+    color.a *= alpha;
+    let style = TextStyle {
+        align,
+        ..TextStyle::label(size, color)
+    };
+
+    let mut vertices = draw_text(&clock::time_text(&view.local_time, format), x, y, &style);
+    if format.date {
+        let date_style = TextStyle {
+            size: size * 0.5,
+            ..style
+        };
+        let date = clock::date_text(&view.local_time);
+        vertices.extend(draw_text(&date, x, y - size * 0.6, &date_style));
+    }
+    vertices
+}
+
+fn stats_page(
+    x: f32,
+    y: f32,
+    size: f32,
+    row_spacing: f32,
+    width: f32,
+    view: &HudView,
+) -> Vec<Vertex> {
+    const PAGE_TIME: f64 = 10.0; // Seconds each page is shown
+    let label_style = TextStyle::label(size, view.color);
+    let value_style = TextStyle {
+        align: Align::Right,
+        ..TextStyle::number(size, view.color)
+    };
+
+    let lifetime_page = (view.session.runtime / PAGE_TIME) as u64 % 2 == 1;
+    let (title, stats) = if lifetime_page {
+        ("LIFETIME", view.lifetime)
+    } else {
+        ("SESSION", view.session)
+    };
+
+    let whole = NumberFormat::default();
+    let rows = [
+        ("SHOTS", stats.shots_fired as f64, whole),
+        ("HITS", stats.shots_hit as f64, whole),
+        (
+            "ACCURACY",
+            stats.accuracy() as f64 * 100.0,
+            NumberFormat::decimal(1),
+        ),
+        (
+            "LARGE ASTEROIDS",
+            stats.asteroids_destroyed[2] as f64,
+            whole,
+        ),
+        (
+            "MEDIUM ASTEROIDS",
+            stats.asteroids_destroyed[1] as f64,
+            whole,
+        ),
+        (
+            "SMALL ASTEROIDS",
+            stats.asteroids_destroyed[0] as f64,
+            whole,
+        ),
+        ("LARGE SAUCERS", stats.large_saucers_destroyed as f64, whole),
+        ("SMALL SAUCERS", stats.small_saucers_destroyed as f64, whole),
+        ("ASTEROID DEATHS", stats.deaths_by_asteroid as f64, whole),
+        ("SAUCER DEATHS", stats.deaths_by_saucer as f64, whole),
+        ("SHOT DOWN", stats.deaths_by_saucer_bullet as f64, whole),
+        ("LONGEST LIFE", stats.longest_life.floor() as f64, whole),
+        ("BEST SCORE", stats.best_score as f64, whole),
+        ("MINUTES", (stats.runtime / 60.0).floor(), whole),
+    ];
+
+    let mut vertices = draw_text(title, x, y, &label_style);
+    for (i, (label, value, format)) in rows.into_iter().enumerate() {
+        let row_y = y - (i + 1) as f32 * row_spacing;
+        vertices.extend(draw_text(label, x, row_y, &label_style));
+        vertices.extend(draw_number(value, &format, x + width, row_y, &value_style));
+    }
+
+    vertices
+}
@@ -1,36 +1,86 @@
+pub mod attract;
+pub mod config;
+pub mod env;
+pub mod eval;
+pub mod feed;
 pub mod game;
+pub mod grid;
+pub mod hud;
 pub mod macos;
+pub mod marquee;
+pub mod profiler;
+#[cfg(feature = "python")]
+mod python;
 pub mod renderer;
+pub mod tuner;
 
+use renderer::{Frame, Layer, RenderBackend, RenderError};
 use winit::window::Window;
 
-pub struct AsteroidsScreensaver<'a> {
-    pub renderer: renderer::Renderer<'a>,
+// The screensaver: game, attract mode, HUD and overlays, drawn through any RenderBackend.
+// `new` opens the wgpu renderer on a window; `with_backend` takes any other output, such
+// as a HeadlessBackend to run without a window or a SoftwareRenderer to draw on the CPU.
+pub struct AsteroidsScreensaver<B: RenderBackend> {
+    pub renderer: B,
     pub game_state: game::GameState,
+    pub attract: attract::Attract,
+    pub hud: hud::Hud,
+    pub marquee: marquee::Marquee,
+    pub feed: Option<feed::Feed>, // Live items shown as labelled asteroids
+    pub show_profiler: bool,      // Frame-time graph over the game
 }
 
-impl<'a> AsteroidsScreensaver<'a> {
-    pub async fn new(window: &'a Window) -> Self {
-        let renderer = renderer::Renderer::new(window).await;
-        let game_state = game::GameState::new();
+impl<'a> AsteroidsScreensaver<renderer::Renderer<'a>> {
+    pub async fn new(window: &'a Window) -> Result<Self, RenderError> {
+        Ok(Self::with_backend(renderer::Renderer::new(window).await?))
+    }
+}
 
+impl<B: RenderBackend> AsteroidsScreensaver<B> {
+    pub fn with_backend(renderer: B) -> Self {
         Self {
             renderer,
-            game_state,
+            game_state: game::GameState::new(),
+            attract: attract::Attract::default(),
+            hud: hud::Hud::default(),
+            marquee: marquee::Marquee::default(),
+            feed: None,
+            show_profiler: false,
         }
     }
 
     pub fn update(&mut self, delta_time: f32) {
-        self.game_state.update(delta_time);
+        profiler::begin_frame();
+        let _span = profiler::span("update");
+        self.attract.update(&mut self.game_state, delta_time);
+        self.marquee.update(delta_time);
+        if let Some(feed) = &mut self.feed {
+            feed.update(&mut self.game_state, delta_time);
+        }
+    }
+
+    // Everything on screen this frame
+    pub fn frame(&self) -> Frame {
+        let _span = profiler::span("build_frame");
+        let mut frame = Frame::default();
+        self.attract
+            .draw_frame(&self.game_state, &self.hud, &mut frame);
+        let hud_color = self.game_state.hud_color;
+        frame.push_vertices(Layer::Overlay, &self.marquee.get_vertices(hud_color));
+        if self.show_profiler {
+            frame.push_vertices(Layer::Overlay, &profiler::overlay_vertices(hud_color));
+        }
+        frame
     }
 
-    pub fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
-        self.renderer.render(&self.game_state)
+    pub fn render(&mut self) -> Result<(), RenderError> {
+        let _span = profiler::span("render");
+        let frame = self.frame();
+        self.renderer.render_frame(&frame)
     }
 
-    pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
-        self.renderer.resize(new_size);
-        self.game_state
-            .resize(new_size.width as f32, new_size.height as f32);
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.renderer.resize(width, height);
+        self.game_state.resize(width as f32, height as f32);
     }
 }
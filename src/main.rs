@@ -1,11 +1,214 @@
+use asteroids_screensaver::config::{self, Config};
+use asteroids_screensaver::game::{GameState, ShipInput};
+use asteroids_screensaver::profiler;
+use asteroids_screensaver::renderer::raster::{SoftwareRenderer, MAX_IMAGE_SIDE};
+use asteroids_screensaver::renderer::software_window::SoftwareWindow;
+use asteroids_screensaver::renderer::{RenderBackend, RenderError};
 use asteroids_screensaver::AsteroidsScreensaver;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
-use winit::{event::*, event_loop::EventLoop, window::WindowBuilder};
+use winit::keyboard::{KeyCode, PhysicalKey};
+use winit::{
+    event::*,
+    event_loop::EventLoop,
+    window::{Window, WindowBuilder},
+};
+
+// Keys currently held down in play mode
+#[derive(Default)]
+struct KeyboardControls {
+    left: bool,
+    right: bool,
+    thrust: bool,
+    fire: bool,
+    hyperspace: bool,
+}
+
+impl KeyboardControls {
+    fn handle_key(&mut self, code: KeyCode, pressed: bool) {
+        match code {
+            KeyCode::ArrowLeft | KeyCode::KeyA => self.left = pressed,
+            KeyCode::ArrowRight | KeyCode::KeyD => self.right = pressed,
+            KeyCode::ArrowUp | KeyCode::KeyW => self.thrust = pressed,
+            KeyCode::Space => self.fire = pressed,
+            KeyCode::ShiftLeft | KeyCode::ShiftRight => self.hyperspace = pressed,
+            _ => {}
+        }
+    }
+
+    fn to_input(&self) -> ShipInput {
+        let mut rotate = 0.0;
+        if self.left {
+            rotate += 1.0;
+        }
+        if self.right {
+            rotate -= 1.0;
+        }
+
+        ShipInput {
+            rotate,
+            thrust: self.thrust,
+            fire: self.fire,
+            hyperspace: self.hyperspace,
+        }
+    }
+}
+
+// Command line options: [--config <path>] [--play] [--idle <seconds>] [--debug]
+// [--snapshot <png> [--size <width>x<height>] [--seconds <n>]]
+// Options given here override the config file
+struct Options {
+    config_path: Option<PathBuf>,
+    play: bool,
+    idle_seconds: Option<f32>,
+    debug: bool,
+    snapshot_path: Option<PathBuf>, // Render one frame on the CPU to this PNG and exit
+    snapshot_size: (u32, u32),
+    snapshot_seconds: f32, // Of play before the snapshot is taken
+}
+
+impl Options {
+    fn from_args() -> Self {
+        let mut options = Options {
+            config_path: None,
+            play: false,
+            idle_seconds: None,
+            debug: false,
+            snapshot_path: None,
+            snapshot_size: (640, 400),
+            snapshot_seconds: 20.0,
+        };
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--config" => {
+                    options.config_path = args.next().map(PathBuf::from);
+                    if options.config_path.is_none() {
+                        eprintln!("--config expects a file path");
+                    }
+                }
+                "--play" => options.play = true,
+                "--debug" => options.debug = true,
+                "--idle" => {
+                    options.idle_seconds = args.next().and_then(|s| s.parse().ok());
+                    if options.idle_seconds.is_none() {
+                        eprintln!("--idle expects a number of seconds");
+                    }
+                }
+                "--snapshot" => {
+                    options.snapshot_path = args.next().map(PathBuf::from);
+                    if options.snapshot_path.is_none() {
+                        eprintln!("--snapshot expects a file path");
+                    }
+                }
+                "--size" => match args.next().as_deref().and_then(parse_size) {
+                    Some(size) => options.snapshot_size = size,
+                    None => eprintln!(
+                        "--size expects <width>x<height>, each up to {}",
+                        MAX_IMAGE_SIDE
+                    ),
+                },
+                "--seconds" => match args.next().and_then(|s| s.parse().ok()) {
+                    Some(seconds) => options.snapshot_seconds = seconds,
+                    None => eprintln!("--seconds expects a number"),
+                },
+                _ => eprintln!("Ignoring unknown argument: {}", arg),
+            }
+        }
+
+        options
+    }
+}
+
+fn parse_size(text: &str) -> Option<(u32, u32)> {
+    let (width, height) = text.split_once('x')?;
+    let size: (u32, u32) = (width.parse().ok()?, height.parse().ok()?);
+    let valid = |side: u32| (1..=MAX_IMAGE_SIDE).contains(&side);
+    (valid(size.0) && valid(size.1)).then_some(size)
+}
+
+// Lifetime stats are saved this often, so little is lost if the process is killed
+const STATS_SAVE_INTERVAL: f32 = 60.0;
+
+fn save_lifetime_stats(game_state: &GameState, path: Option<&Path>) {
+    if let Some(path) = path {
+        if let Err(e) = config::save_stats(&game_state.lifetime_totals(), path) {
+            eprintln!("Could not save stats to {}: {}", path.display(), e);
+        }
+    }
+}
+
+fn write_profile_trace(path: Option<&Path>) {
+    if let Some(path) = path {
+        if let Err(e) = profiler::write_chrome_trace(path) {
+            eprintln!("Could not write profile trace to {}: {}", path.display(), e);
+        }
+    }
+}
+
+// Settings from the config file and command line that apply to any backend
+fn configure<B: RenderBackend>(
+    screensaver: &mut AsteroidsScreensaver<B>,
+    config: &Config,
+    options: &Options,
+) {
+    config.apply(&mut screensaver.game_state);
+    config.apply_attract(&mut screensaver.attract);
+    config.apply_hud(&mut screensaver.hud);
+    config.apply_marquee(&mut screensaver.marquee);
+    if options.play {
+        screensaver.game_state.play_mode = true;
+    }
+    if let Some(seconds) = options.idle_seconds {
+        screensaver.game_state.ai_takeover_delay = seconds;
+    }
+    if options.debug {
+        screensaver.game_state.show_debug = true;
+    }
+}
+
+// Play for a while without a window, drawing on the CPU, and save the last frame, e.g.
+// as a thumbnail. Needs no GPU.
+fn write_snapshot(config: &Config, options: &Options, path: &Path) {
+    let (width, height) = options.snapshot_size;
+    let renderer = match SoftwareRenderer::new(width, height) {
+        Ok(renderer) => renderer,
+        Err(e) => {
+            eprintln!("Could not take a snapshot: {}", e);
+            return;
+        }
+    };
+    let mut screensaver = AsteroidsScreensaver::with_backend(renderer);
+    configure(&mut screensaver, config, options);
+    screensaver.resize(width, height);
+
+    const STEP: f32 = 1.0 / 60.0;
+    let steps = (options.snapshot_seconds / STEP).round().max(1.0) as u32;
+    for _ in 0..steps {
+        screensaver.update(STEP);
+    }
+    if let Err(e) = screensaver.render() {
+        eprintln!("Render error: {}", e);
+    }
+    match screensaver.renderer.render_to_png(path) {
+        Ok(()) => println!("Wrote {}", path.display()),
+        Err(e) => eprintln!("Could not write snapshot to {}: {}", path.display(), e),
+    }
+}
 
 fn main() {
     env_logger::init();
 
+    let options = Options::from_args();
+    let config = Config::load_or_default(options.config_path.as_deref());
+
+    if let Some(path) = &options.snapshot_path {
+        write_snapshot(&config, &options, path);
+        return;
+    }
+
     let event_loop = EventLoop::new().unwrap();
 
     // Get primary monitor size for automatic adaptation
@@ -25,51 +228,155 @@ fn main() {
             .unwrap(),
     );
 
-    let mut screensaver = pollster::block_on(AsteroidsScreensaver::new(&window));
-    let mut last_frame = Instant::now();
-    let window_clone = Arc::clone(&window);
-
-    event_loop.run(move |event, elwt| {
-        match event {
-            Event::WindowEvent {
-                ref event,
-                window_id,
-            } if window_id == window_clone.id() => match event {
-                WindowEvent::CloseRequested
-                | WindowEvent::KeyboardInput {
-                    event:
-                        KeyEvent {
-                            state: ElementState::Pressed,
-                            physical_key: winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Escape),
-                            ..
-                        },
-                    ..
-                } => elwt.exit(),
-                WindowEvent::Resized(physical_size) => {
-                    screensaver.resize(*physical_size);
+    let gpu_screensaver = pollster::block_on(AsteroidsScreensaver::new(&window));
+    match gpu_screensaver {
+        Ok(screensaver) => run(event_loop, &window, screensaver, &config, &options),
+        Err(e) => {
+            // No usable GPU: draw on the CPU and show the frames in the same window
+            eprintln!("Could not start the GPU renderer: {}", e);
+            eprintln!("Drawing on the CPU instead");
+            match SoftwareWindow::new(&window) {
+                Ok(renderer) => {
+                    let screensaver = AsteroidsScreensaver::with_backend(renderer);
+                    run(event_loop, &window, screensaver, &config, &options);
+                }
+                Err(e) => {
+                    eprintln!("Could not start the software renderer: {}", e);
+                    std::process::exit(1);
                 }
-                WindowEvent::RedrawRequested => {
-                    let now = Instant::now();
-                    let delta_time = (now - last_frame).as_secs_f32();
-                    last_frame = now;
-
-                    screensaver.update(delta_time);
-
-                    match screensaver.render() {
-                        Ok(_) => {}
-                        Err(wgpu::SurfaceError::Lost) => screensaver.resize(window_clone.inner_size()),
-                        Err(wgpu::SurfaceError::OutOfMemory) => elwt.exit(),
-                        Err(e) => eprintln!("Render error: {:?}", e),
+            }
+        }
+    }
+}
+
+// Play in the window until it is closed
+fn run<B: RenderBackend>(
+    event_loop: EventLoop<()>,
+    window: &Window,
+    mut screensaver: AsteroidsScreensaver<B>,
+    config: &Config,
+    options: &Options,
+) {
+    configure(&mut screensaver, config, options);
+    screensaver.feed = config.open_feed();
+
+    // Lifetime totals from earlier sessions
+    let stats_path = config.lifetime_stats_path();
+    if let Some(path) = &stats_path {
+        match config::load_stats(path) {
+            Ok(stats) => screensaver.game_state.lifetime_stats = stats,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => eprintln!("Could not read stats {}: {}", path.display(), e),
+        }
+    }
+    let mut time_since_stats_save = 0.0;
+
+    // Profiling runs for the graph and for a trace written on exit
+    let trace_path = config.profile_trace.clone();
+    screensaver.show_profiler = config.show_profiler;
+    profiler::set_enabled(screensaver.show_profiler || trace_path.is_some());
+
+    let mut controls = KeyboardControls::default();
+    let mut last_frame = Instant::now();
+
+    event_loop
+        .run(move |event, elwt| {
+            match event {
+                Event::WindowEvent {
+                    ref event,
+                    window_id,
+                } if window_id == window.id() => match event {
+                    WindowEvent::CloseRequested
+                    | WindowEvent::KeyboardInput {
+                        event:
+                            KeyEvent {
+                                state: ElementState::Pressed,
+                                physical_key: PhysicalKey::Code(KeyCode::Escape),
+                                ..
+                            },
+                        ..
+                    } => {
+                        save_lifetime_stats(&screensaver.game_state, stats_path.as_deref());
+                        write_profile_trace(trace_path.as_deref());
+                        elwt.exit();
+                    }
+                    // F3 toggles the debug overlay in any mode
+                    WindowEvent::KeyboardInput {
+                        event:
+                            KeyEvent {
+                                state: ElementState::Pressed,
+                                physical_key: PhysicalKey::Code(KeyCode::F3),
+                                repeat: false,
+                                ..
+                            },
+                        ..
+                    } => {
+                        screensaver.game_state.show_debug = !screensaver.game_state.show_debug;
+                    }
+                    // F4 toggles the frame-time graph; profiling runs while it is shown
+                    WindowEvent::KeyboardInput {
+                        event:
+                            KeyEvent {
+                                state: ElementState::Pressed,
+                                physical_key: PhysicalKey::Code(KeyCode::F4),
+                                repeat: false,
+                                ..
+                            },
+                        ..
+                    } => {
+                        screensaver.show_profiler = !screensaver.show_profiler;
+                        profiler::set_enabled(screensaver.show_profiler || trace_path.is_some());
                     }
+                    WindowEvent::KeyboardInput {
+                        event:
+                            KeyEvent {
+                                state,
+                                physical_key: PhysicalKey::Code(code),
+                                repeat: false,
+                                ..
+                            },
+                        ..
+                    } if screensaver.game_state.play_mode => {
+                        controls.handle_key(*code, *state == ElementState::Pressed);
+                        screensaver.game_state.set_input(controls.to_input());
+                        // The game latches the press; holding the key must not send it again
+                        controls.hyperspace = false;
+                    }
+                    WindowEvent::Resized(physical_size) => {
+                        screensaver.resize(physical_size.width, physical_size.height);
+                    }
+                    WindowEvent::RedrawRequested => {
+                        let now = Instant::now();
+                        let delta_time = (now - last_frame).as_secs_f32();
+                        last_frame = now;
+
+                        screensaver.update(delta_time);
+
+                        time_since_stats_save += delta_time;
+                        if time_since_stats_save > STATS_SAVE_INTERVAL {
+                            save_lifetime_stats(&screensaver.game_state, stats_path.as_deref());
+                            time_since_stats_save = 0.0;
+                        }
 
-                    window_clone.request_redraw();
+                        match screensaver.render() {
+                            Ok(_) => {}
+                            Err(RenderError::Lost) => {
+                                let size = window.inner_size();
+                                screensaver.resize(size.width, size.height)
+                            }
+                            Err(RenderError::OutOfMemory) => elwt.exit(),
+                            Err(e) => eprintln!("Render error: {}", e),
+                        }
+
+                        window.request_redraw();
+                    }
+                    _ => {}
+                },
+                Event::AboutToWait => {
+                    window.request_redraw();
                 }
                 _ => {}
-            },
-            Event::AboutToWait => {
-                window_clone.request_redraw();
             }
-            _ => {}
-        }
-    }).unwrap();
+        })
+        .unwrap();
 }
@@ -0,0 +1,169 @@
+// A user message shown over the game in vector text, either scrolling across the screen
+// like a ticker or flashing in place. The text comes from the config or from a file
+// that is re-read whenever it changes, so it can be updated while the screensaver runs.
+
+use crate::renderer::layout::{draw_text, line_width};
+use crate::renderer::{Align, Color, TextStyle, Vertex};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+const FILE_CHECK_INTERVAL: f32 = 1.0; // Seconds between checks of the message file
+const FLASHES_PER_SHOWING: u32 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MarqueeMode {
+    Scroll, // Enters on the right and leaves on the left
+    Flash,  // Centred, blinking on and off
+}
+
+impl MarqueeMode {
+    pub fn from_name(name: &str) -> Option<MarqueeMode> {
+        match name.to_ascii_lowercase().as_str() {
+            "scroll" => Some(MarqueeMode::Scroll),
+            "flash" => Some(MarqueeMode::Flash),
+            _ => None,
+        }
+    }
+}
+
+pub struct Marquee {
+    pub text: String,          // Empty when there is no message
+    pub file: Option<PathBuf>, // Message file; replaces `text` when it can be read
+    pub mode: MarqueeMode,
+    pub speed: f32, // Scroll: screen units per second. Flash: flashes per second
+    pub y: f32,     // Baseline, in normalized device coordinates
+    pub size: f32,
+    pub repeat: Option<u32>, // Number of showings, or None to repeat forever
+    pub gap: f32,            // Seconds between showings
+    time: f32,               // Into the current showing, or the gap after it
+    showings: u32,
+    file_modified: Option<SystemTime>,
+    time_since_file_check: f32,
+}
+
+impl Default for Marquee {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            file: None,
+            mode: MarqueeMode::Scroll,
+            speed: 0.3,
+            y: -0.85,
+            size: 0.06,
+            repeat: None,
+            gap: 10.0,
+            time: 0.0,
+            showings: 0,
+            file_modified: None,
+            time_since_file_check: FILE_CHECK_INTERVAL,
+        }
+    }
+}
+
+impl Marquee {
+    // Vertical position by name: top, center or bottom
+    pub fn position_y(name: &str) -> Option<f32> {
+        match name.to_ascii_lowercase().as_str() {
+            "top" => Some(0.75),
+            "center" | "centre" => Some(0.0),
+            "bottom" => Some(-0.85),
+            _ => None,
+        }
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.time_since_file_check += delta_time;
+        if self.time_since_file_check >= FILE_CHECK_INTERVAL {
+            self.time_since_file_check = 0.0;
+            self.reload_file();
+        }
+
+        if self.text.is_empty() || self.finished() {
+            return;
+        }
+        self.time += delta_time;
+        if self.time >= self.showing_time() + self.gap {
+            self.time = 0.0;
+            self.showings += 1;
+        }
+    }
+
+    fn finished(&self) -> bool {
+        self.repeat.is_some_and(|repeat| self.showings >= repeat)
+    }
+
+    // Re-read the message file if it has changed since it was last read
+    fn reload_file(&mut self) {
+        let Some(path) = &self.file else {
+            return;
+        };
+        let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+        if modified.is_none() || modified == self.file_modified {
+            return;
+        }
+        self.file_modified = modified;
+
+        match std::fs::read_to_string(path) {
+            Ok(contents) => {
+                // Lines are joined so the whole file scrolls past as one message
+                let lines: Vec<&str> = contents
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .collect();
+                self.text = lines.join("   ");
+                // A new message starts from the beginning and gets its full repeat count
+                self.time = 0.0;
+                self.showings = 0;
+            }
+            Err(e) => eprintln!("Could not read marquee file {}: {}", path.display(), e),
+        }
+    }
+
+    fn style(&self, color: Color) -> TextStyle {
+        TextStyle {
+            align: Align::Center,
+            ..TextStyle::label(self.size, color)
+        }
+    }
+
+    // At least a little, so a showing always ends
+    fn speed(&self) -> f32 {
+        self.speed.max(0.01)
+    }
+
+    // Seconds the message is on screen each time it is shown
+    fn showing_time(&self) -> f32 {
+        let speed = self.speed();
+        match self.mode {
+            MarqueeMode::Scroll => {
+                let width = line_width(&self.text, &self.style(Color::WHITE));
+                (2.0 + width) / speed
+            }
+            MarqueeMode::Flash => FLASHES_PER_SHOWING as f32 / speed,
+        }
+    }
+
+    pub fn get_vertices(&self, color: Color) -> Vec<Vertex> {
+        if self.text.is_empty() || self.finished() || self.time >= self.showing_time() {
+            return Vec::new();
+        }
+
+        let style = self.style(color);
+        match self.mode {
+            MarqueeMode::Scroll => {
+                let width = line_width(&self.text, &style);
+                let x = 1.0 + width * 0.5 - self.time * self.speed();
+                draw_text(&self.text, x, self.y, &style)
+            }
+            MarqueeMode::Flash => {
+                // On for the first half of each flash
+                if (self.time * self.speed()).fract() < 0.5 {
+                    draw_text(&self.text, 0.0, self.y, &style)
+                } else {
+                    Vec::new()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,274 @@
+// Frame-time profiler: named timing spans within each frame, kept for the last
+// HISTORY_FRAMES frames, drawn as a frame-time graph and written out as a Chrome trace
+// (load it in chrome://tracing or https://ui.perfetto.dev).
+//
+// Instrumented code opens a span with a guard that records when it is dropped:
+//
+//   let _span = profiler::span("check_collisions");
+//
+// The profiler is per thread and does nothing until enabled, so instrumentation costs one
+// flag check in the headless tools.
+
+use crate::renderer::layout::draw_text;
+use crate::renderer::{Color, TextStyle, Vertex};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub const HISTORY_FRAMES: usize = 600; // Ten seconds at 60 FPS
+const GRAPH_MAX_MS: f32 = 50.0; // Frame time at the top of the graph
+const HITCH_MS: f32 = 25.0; // Frames slower than this are drawn in red
+const TEXT_SIZE: f32 = 0.025;
+
+// A timed section of a frame
+#[derive(Clone, Copy, Debug)]
+pub struct SpanRecord {
+    pub name: &'static str,
+    pub start: Instant,
+    pub duration: Duration,
+    pub depth: usize, // Spans open around this one
+}
+
+#[derive(Clone, Debug)]
+pub struct Frame {
+    pub start: Instant,
+    pub duration: Duration, // Until the next frame began
+    pub spans: Vec<SpanRecord>,
+}
+
+struct Profiler {
+    history: VecDeque<Frame>,
+    current: Option<Frame>,
+    depth: usize,
+    span_names: Vec<(&'static str, usize)>, // Each span name once, in the order first opened, with its depth
+}
+
+thread_local! {
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+    static PROFILER: RefCell<Profiler> = const {
+        RefCell::new(Profiler {
+            history: VecDeque::new(),
+            current: None,
+            depth: 0,
+            span_names: Vec::new(),
+        })
+    };
+}
+
+pub fn set_enabled(enabled: bool) {
+    let was_enabled = ENABLED.with(|e| e.replace(enabled));
+    PROFILER.with(|p| {
+        let mut p = p.borrow_mut();
+        if !enabled {
+            p.current = None;
+        } else if !was_enabled {
+            // Frames from before the pause would sit next to new ones with a gap between
+            p.history.clear();
+        }
+    });
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.with(|e| e.get())
+}
+
+// End the current frame, adding it to the history, and start the next
+pub fn begin_frame() {
+    if !is_enabled() {
+        return;
+    }
+    let now = Instant::now();
+    PROFILER.with(|p| {
+        let mut p = p.borrow_mut();
+        if let Some(mut frame) = p.current.take() {
+            frame.duration = now - frame.start;
+            if p.history.len() == HISTORY_FRAMES {
+                p.history.pop_front();
+            }
+            p.history.push_back(frame);
+        }
+        p.current = Some(Frame {
+            start: now,
+            duration: Duration::ZERO,
+            spans: Vec::new(),
+        });
+        p.depth = 0;
+    });
+}
+
+// Records the time from its creation until it is dropped
+pub struct Span {
+    name: &'static str,
+    start: Option<Instant>, // None while the profiler is off
+}
+
+pub fn span(name: &'static str) -> Span {
+    let start = is_enabled().then(|| {
+        PROFILER.with(|p| {
+            let mut p = p.borrow_mut();
+            if !p.span_names.iter().any(|(n, _)| *n == name) {
+                let depth = p.depth;
+                p.span_names.push((name, depth));
+            }
+            p.depth += 1;
+        });
+        Instant::now()
+    });
+    Span { name, start }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some(start) = self.start else {
+            return;
+        };
+        let duration = start.elapsed();
+        PROFILER.with(|p| {
+            let mut p = p.borrow_mut();
+            p.depth = p.depth.saturating_sub(1);
+            let depth = p.depth;
+            if let Some(frame) = &mut p.current {
+                frame.spans.push(SpanRecord {
+                    name: self.name,
+                    start,
+                    duration,
+                    depth,
+                });
+            }
+        });
+    }
+}
+
+// Completed frames, oldest first
+pub fn history() -> Vec<Frame> {
+    PROFILER.with(|p| p.borrow().history.iter().cloned().collect())
+}
+
+fn ms(duration: Duration) -> f32 {
+    duration.as_secs_f32() * 1000.0
+}
+
+// Frame-time graph over the history with reference lines at 60 and 30 FPS, and the
+// average and worst time of each span, in the bottom-right corner. Works on the history
+// in place, as it is drawn every frame while shown.
+pub fn overlay_vertices(color: Color) -> Vec<Vertex> {
+    PROFILER.with(|p| {
+        let p = p.borrow();
+        overlay_from(&p.history, &p.span_names, color)
+    })
+}
+
+fn overlay_from(
+    frames: &VecDeque<Frame>,
+    span_names: &[(&'static str, usize)],
+    color: Color,
+) -> Vec<Vertex> {
+    let mut vertices = Vec::new();
+    if frames.is_empty() {
+        return vertices;
+    }
+
+    let (left, bottom, width, height) = (0.45, -0.95, 0.5, 0.25);
+    let line = |vertices: &mut Vec<Vertex>, from: (f32, f32), to: (f32, f32), color: Color| {
+        let color = color.to_array();
+        vertices.push(Vertex {
+            position: [from.0, from.1],
+            color,
+        });
+        vertices.push(Vertex {
+            position: [to.0, to.1],
+            color,
+        });
+    };
+    let graph_y = |frame_ms: f32| bottom + (frame_ms / GRAPH_MAX_MS).min(1.0) * height;
+
+    // One vertical stroke per frame, newest on the right
+    let step = width / HISTORY_FRAMES as f32;
+    let offset = HISTORY_FRAMES - frames.len();
+    for (i, frame) in frames.iter().enumerate() {
+        let x = left + (offset + i) as f32 * step;
+        let frame_ms = ms(frame.duration);
+        let bar = if frame_ms > HITCH_MS {
+            Color::RED
+        } else {
+            color
+        };
+        line(&mut vertices, (x, bottom), (x, graph_y(frame_ms)), bar);
+    }
+
+    let guide = Color { a: 0.5, ..color };
+    for fps in [60.0, 30.0] {
+        let y = graph_y(1000.0 / fps);
+        line(&mut vertices, (left, y), (left + width, y), guide);
+    }
+    line(&mut vertices, (left, bottom), (left + width, bottom), guide);
+
+    // Summary and per-span lines above the graph
+    let total: Duration = frames.iter().map(|f| f.duration).sum();
+    let average = ms(total) / frames.len() as f32;
+    let worst = frames.iter().map(|f| ms(f.duration)).fold(0.0, f32::max);
+    let mut lines = vec![format!(
+        "FPS {:.0}  FRAME {:.1} MS  WORST {:.1} MS",
+        1000.0 / average.max(0.001),
+        average,
+        worst
+    )];
+    for &(name, depth) in span_names {
+        let (span_total, span_worst) = frames
+            .iter()
+            .flat_map(|f| f.spans.iter().filter(|s| s.name == name))
+            .fold((0.0, 0.0_f32), |(total, worst), s| {
+                let span_ms = ms(s.duration);
+                (total + span_ms, worst.max(span_ms))
+            });
+        lines.push(format!(
+            "{}{}  {:.2}  MAX {:.2}",
+            "  ".repeat(depth),
+            name,
+            span_total / frames.len() as f32,
+            span_worst
+        ));
+    }
+
+    let style = TextStyle::label(TEXT_SIZE, color);
+    let line_spacing = TEXT_SIZE * 1.5;
+    let top = bottom + height + 0.03 + (lines.len() - 1) as f32 * line_spacing;
+    for (i, text) in lines.iter().enumerate() {
+        vertices.extend(draw_text(text, left, top - i as f32 * line_spacing, &style));
+    }
+    vertices
+}
+
+// Write the history and the frame in progress as Chrome trace events: one complete ("X")
+// event per frame and per span, in microseconds from the start of the oldest frame
+pub fn write_chrome_trace(path: &Path) -> std::io::Result<()> {
+    // The frame still in progress goes in too, up to now
+    let mut frames = history();
+    let current = PROFILER.with(|p| p.borrow().current.clone());
+    if let Some(mut frame) = current {
+        frame.duration = frame.start.elapsed();
+        frames.push(frame);
+    }
+    let Some(epoch) = frames.first().map(|f| f.start) else {
+        return std::fs::write(path, "{\"traceEvents\": []}\n");
+    };
+    let micros = |instant: Instant| (instant - epoch).as_secs_f64() * 1_000_000.0;
+
+    let mut events = Vec::new();
+    for frame in &frames {
+        let spans = std::iter::once(("frame", frame.start, frame.duration))
+            .chain(frame.spans.iter().map(|s| (s.name, s.start, s.duration)));
+        for (name, start, duration) in spans {
+            events.push(format!(
+                "{{\"name\": \"{}\", \"ph\": \"X\", \"ts\": {:.1}, \"dur\": {:.1}, \"pid\": 1, \"tid\": 1}}",
+                name,
+                micros(start),
+                duration.as_secs_f64() * 1_000_000.0
+            ));
+        }
+    }
+
+    let text = format!("{{\"traceEvents\": [\n{}\n]}}\n", events.join(",\n"));
+    std::fs::write(path, text)
+}
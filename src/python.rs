@@ -0,0 +1,249 @@
+// Python bindings for the headless game (cargo feature "python"). Build with maturin:
+//
+//   maturin develop --release
+//
+//   import asteroids_screensaver as asteroids
+//   env = asteroids.Env(seed=1, config={"ticks_per_step": 4})
+//   observation = env.reset(1)
+//   observation, reward, done, info = env.step(3)   # discrete index or (rotate, thrust, fire, hyperspace)
+//   grid = env.grid(84, 84)                         # numpy float32 occupancy grid around the ship
+//   frame = env.render(320, 240)                    # numpy uint8 array, height x width x 4
+
+use crate::env::{self, Action, Env, EnvSettings};
+use crate::game::physics::WORLD_SIZE;
+use crate::grid::{GridFrame, GridSettings};
+use crate::hud::Hud;
+use crate::renderer::{raster, Color};
+use numpy::{PyArray1, PyArray2, PyArray3, PyArrayMethods};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+// (observation, reward, done, info), as returned by gym's step()
+type StepResult<'py> = (Bound<'py, PyArray1<f32>>, f32, bool, Bound<'py, PyDict>);
+
+#[pyclass(name = "Env", module = "asteroids_screensaver")]
+struct PyEnv {
+    env: Env,
+}
+
+#[pymethods]
+impl PyEnv {
+    // `config` overrides EnvSettings fields by name, e.g. {"nearest_objects": 16}
+    #[new]
+    #[pyo3(signature = (seed = 0, config = None))]
+    fn new(seed: u64, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let mut settings = EnvSettings::default();
+        if let Some(config) = config {
+            for (key, value) in config.iter() {
+                let key: String = key.extract()?;
+                match key.as_str() {
+                    "delta_time" => settings.delta_time = value.extract()?,
+                    "ticks_per_step" => settings.ticks_per_step = value.extract()?,
+                    "max_steps" => settings.max_steps = value.extract()?,
+                    "nearest_objects" => settings.nearest_objects = value.extract()?,
+                    "end_on_death" => settings.end_on_death = value.extract()?,
+                    "score_reward" => settings.score_reward = value.extract()?,
+                    "survival_reward" => settings.survival_reward = value.extract()?,
+                    "death_penalty" => settings.death_penalty = value.extract()?,
+                    _ => {
+                        return Err(PyValueError::new_err(format!(
+                            "unknown config key '{}'",
+                            key
+                        )))
+                    }
+                }
+            }
+        }
+
+        let mut env = Env::new(settings);
+        env.reset(seed);
+        Ok(Self { env })
+    }
+
+    #[getter]
+    fn observation_size(&self) -> usize {
+        self.env.observation_size()
+    }
+
+    #[getter]
+    fn action_count(&self) -> usize {
+        env::DISCRETE_ACTIONS
+    }
+
+    #[getter]
+    fn score(&self) -> u32 {
+        self.env.game.score
+    }
+
+    #[getter]
+    fn deaths(&self) -> u32 {
+        self.env.game.deaths
+    }
+
+    fn reset<'py>(&mut self, py: Python<'py>, seed: u64) -> Bound<'py, PyArray1<f32>> {
+        PyArray1::from_vec(py, self.env.reset(seed))
+    }
+
+    fn step<'py>(
+        &mut self,
+        py: Python<'py>,
+        action: &Bound<'py, PyAny>,
+    ) -> PyResult<StepResult<'py>> {
+        let action = extract_action(action)?;
+        let (observation, reward, done, step_info) = self.env.step(action);
+
+        let info = PyDict::new(py);
+        info.set_item("score", step_info.score)?;
+        info.set_item("deaths", step_info.deaths)?;
+        info.set_item("steps", step_info.steps)?;
+        info.set_item("died", step_info.died)?;
+        info.set_item("truncated", step_info.truncated)?;
+
+        Ok((PyArray1::from_vec(py, observation), reward, done, info))
+    }
+
+    fn observe<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f32>> {
+        PyArray1::from_vec(py, self.env.observe())
+    }
+
+    // Player ship as a dict of its state
+    fn ship<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let ship = &self.env.game.player_ship;
+        let dict = PyDict::new(py);
+        dict.set_item("x", ship.x)?;
+        dict.set_item("y", ship.y)?;
+        dict.set_item("vx", ship.vx)?;
+        dict.set_item("vy", ship.vy)?;
+        dict.set_item("angle", ship.angle)?;
+        dict.set_item("energy", ship.energy)?;
+        dict.set_item("can_shoot", ship.can_shoot())?;
+        dict.set_item("can_hyperspace", ship.can_hyperspace())?;
+        Ok(dict)
+    }
+
+    // One row per asteroid: x, y, vx, vy, radius, size level
+    fn asteroids<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray2<f32>>> {
+        let rows: Vec<Vec<f32>> = self
+            .env
+            .game
+            .asteroids
+            .iter()
+            .map(|a| vec![a.x, a.y, a.vx, a.vy, a.size, a.size_level as f32])
+            .collect();
+        to_array2(py, rows, 6)
+    }
+
+    // One row per saucer: x, y, vx, vy, radius, small (1.0) or large (0.0)
+    fn saucers<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray2<f32>>> {
+        let rows: Vec<Vec<f32>> = self
+            .env
+            .game
+            .saucers
+            .iter()
+            .map(|s| {
+                let small = matches!(s.size, crate::game::SaucerSize::Small);
+                vec![
+                    s.x,
+                    s.y,
+                    s.vx,
+                    s.vy,
+                    s.get_collision_radius(),
+                    if small { 1.0 } else { 0.0 },
+                ]
+            })
+            .collect();
+        to_array2(py, rows, 6)
+    }
+
+    // One row per bullet: x, y, vx, vy, fired by the player (1.0) or a saucer (0.0)
+    fn bullets<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyArray2<f32>>> {
+        let ship_id = self.env.game.player_ship.id;
+        let rows: Vec<Vec<f32>> = self
+            .env
+            .game
+            .bullets
+            .iter()
+            .map(|b| {
+                let player = b.owner_id == ship_id;
+                vec![b.x, b.y, b.vx, b.vy, if player { 1.0 } else { 0.0 }]
+            })
+            .collect();
+        to_array2(py, rows, 5)
+    }
+
+    // Occupancy grid of the game objects: float32 array of height x width, values 0-1
+    #[pyo3(signature = (width = 84, height = 84, ship_centred = true, view_size = WORLD_SIZE))]
+    fn grid<'py>(
+        &self,
+        py: Python<'py>,
+        width: usize,
+        height: usize,
+        ship_centred: bool,
+        view_size: f32,
+    ) -> PyResult<Bound<'py, PyArray2<f32>>> {
+        let settings = GridSettings {
+            width,
+            height,
+            frame: if ship_centred {
+                GridFrame::ShipCentred
+            } else {
+                GridFrame::World
+            },
+            view_size,
+        };
+        let grid = self.env.observe_grid(&settings);
+        PyArray1::from_vec(py, grid.cells).reshape([height, width])
+    }
+
+    // Draw the current frame on the CPU: uint8 array of height x width x RGBA
+    #[pyo3(signature = (width = 320, height = 240))]
+    fn render<'py>(
+        &self,
+        py: Python<'py>,
+        width: u32,
+        height: u32,
+    ) -> PyResult<Bound<'py, PyArray3<u8>>> {
+        let vertices = Hud::default().frame_vertices(&self.env.game);
+        let image = raster::rasterize(&vertices, width, height, Color::BLACK)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        PyArray1::from_vec(py, image.pixels).reshape([height as usize, width as usize, 4])
+    }
+}
+
+// An action is either a discrete index or a (rotate, thrust, fire, hyperspace) tuple
+fn extract_action(action: &Bound<'_, PyAny>) -> PyResult<Action> {
+    if let Ok(index) = action.extract::<usize>() {
+        if index >= env::DISCRETE_ACTIONS {
+            return Err(PyValueError::new_err(format!(
+                "action index must be below {}",
+                env::DISCRETE_ACTIONS
+            )));
+        }
+        return Ok(env::discrete_action(index));
+    }
+
+    let (rotate, thrust, fire, hyperspace) = action.extract::<(f32, bool, bool, bool)>()?;
+    Ok(Action {
+        rotate,
+        thrust,
+        fire,
+        hyperspace,
+    })
+}
+
+// Rows of equal length as a 2D array; an empty list still has `columns` columns
+fn to_array2(
+    py: Python<'_>,
+    rows: Vec<Vec<f32>>,
+    columns: usize,
+) -> PyResult<Bound<'_, PyArray2<f32>>> {
+    let count = rows.len();
+    PyArray1::from_vec(py, rows.concat()).reshape([count, columns])
+}
+
+#[pymodule]
+fn asteroids_screensaver(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyEnv>()?;
+    Ok(())
+}
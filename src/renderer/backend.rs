@@ -0,0 +1,158 @@
+// A frame as a list of coloured line segments on layers over a clear colour, and the
+// RenderBackend trait that draws one. The game describes each frame the same way whatever
+// it is drawn to: the wgpu window, an image, or nothing at all when running headless.
+
+use super::{Color, Vertex};
+use std::fmt;
+
+// Draw order, back to front. Within a layer segments are drawn in the order they were added.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    Game,    // Ship, asteroids, saucers and bullets; attract mode screens
+    Hud,     // Score, gauges and other HUD elements, over the game
+    Overlay, // Debug overlay, profiler graph and marquee, over everything
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Segment {
+    pub from: [f32; 2], // Normalized device coordinates, -1..1
+    pub to: [f32; 2],
+    pub color: Color,
+    pub layer: Layer,
+}
+
+#[derive(Clone, Debug)]
+pub struct Frame {
+    pub clear_color: Color,
+    pub segments: Vec<Segment>,
+}
+
+impl Default for Frame {
+    fn default() -> Self {
+        Self::new(Color::BLACK)
+    }
+}
+
+impl Frame {
+    pub fn new(clear_color: Color) -> Self {
+        Self {
+            clear_color,
+            segments: Vec::new(),
+        }
+    }
+
+    // Add a line list (pairs of vertices) to a layer. Each segment takes the colour of its
+    // first vertex; an odd vertex at the end is ignored.
+    pub fn push_vertices(&mut self, layer: Layer, vertices: &[Vertex]) {
+        self.segments
+            .extend(vertices.chunks_exact(2).map(|pair| Segment {
+                from: pair[0].position,
+                to: pair[1].position,
+                color: Color::from_array(pair[0].color),
+                layer,
+            }));
+    }
+
+    pub fn push_line(&mut self, layer: Layer, from: [f32; 2], to: [f32; 2], color: Color) {
+        self.segments.push(Segment {
+            from,
+            to,
+            color,
+            layer,
+        });
+    }
+
+    // The frame as one line list in draw order
+    pub fn vertices(&self) -> Vec<Vertex> {
+        let mut segments: Vec<&Segment> = self.segments.iter().collect();
+        segments.sort_by_key(|s| s.layer); // Stable, so order within a layer is kept
+        segments
+            .iter()
+            .flat_map(|s| {
+                let color = s.color.to_array();
+                [
+                    Vertex {
+                        position: s.from,
+                        color,
+                    },
+                    Vertex {
+                        position: s.to,
+                        color,
+                    },
+                ]
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub enum RenderError {
+    Lost,        // The output must be reconfigured, e.g. by resizing it to its current size
+    OutOfMemory, // Unrecoverable
+    Other(String),
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RenderError::Lost => write!(f, "render output lost"),
+            RenderError::OutOfMemory => write!(f, "out of memory"),
+            RenderError::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<wgpu::SurfaceError> for RenderError {
+    fn from(error: wgpu::SurfaceError) -> Self {
+        match error {
+            wgpu::SurfaceError::Lost => RenderError::Lost,
+            wgpu::SurfaceError::OutOfMemory => RenderError::OutOfMemory,
+            e => RenderError::Other(e.to_string()),
+        }
+    }
+}
+
+pub trait RenderBackend {
+    fn render_frame(&mut self, frame: &Frame) -> Result<(), RenderError>;
+
+    // The output size in pixels changed
+    fn resize(&mut self, width: u32, height: u32);
+}
+
+// Keeps the last frame instead of drawing it, for driving the game without a window
+pub struct HeadlessBackend {
+    pub width: u32,
+    pub height: u32,
+    pub last_frame: Option<Frame>,
+    pub frames_rendered: u64,
+}
+
+impl HeadlessBackend {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            last_frame: None,
+            frames_rendered: 0,
+        }
+    }
+}
+
+impl Default for HeadlessBackend {
+    fn default() -> Self {
+        Self::new(1920, 1080)
+    }
+}
+
+impl RenderBackend for HeadlessBackend {
+    fn render_frame(&mut self, frame: &Frame) -> Result<(), RenderError> {
+        self.last_frame = Some(frame.clone());
+        self.frames_rendered += 1;
+        Ok(())
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
@@ -0,0 +1,306 @@
+// Stroke font in the style of the Atari vector games. Glyphs are polylines on a grid
+// 4 units wide and 6 tall (baseline at 0, descenders dip below), and advance 6 units
+// unless they are narrow punctuation. Lowercase letters draw as uppercase, as on the
+// arcade hardware.
+
+use super::{Color, Vertex};
+
+pub const GLYPH_HEIGHT: f32 = 6.0; // Cap height in grid units
+pub const GLYPH_SPACING: f32 = 2.0; // Gap included in each glyph's advance
+
+// Private-use characters for symbols that have no natural Unicode equivalent
+pub const SHIP_ICON: char = '\u{E000}';
+pub const SAUCER_ICON: char = '\u{E001}';
+
+type Strokes = &'static [&'static [(f32, f32)]];
+
+#[derive(Clone, Copy)]
+pub struct Glyph {
+    pub strokes: Strokes,
+    pub advance: f32, // Distance to the next glyph's origin, in grid units
+}
+
+impl Glyph {
+    const fn new(strokes: Strokes) -> Glyph {
+        Glyph {
+            strokes,
+            advance: 6.0,
+        }
+    }
+
+    const fn narrow(strokes: Strokes, advance: f32) -> Glyph {
+        Glyph { strokes, advance }
+    }
+}
+
+// Drawn for characters the font does not have, so missing glyphs are easy to spot
+const MISSING: Glyph = Glyph::new(&[
+    &[(0.0, 0.0), (0.0, 6.0), (4.0, 6.0), (4.0, 0.0), (0.0, 0.0)],
+    &[(0.0, 0.0), (4.0, 6.0)],
+]);
+
+pub fn glyph(ch: char) -> Option<Glyph> {
+    let glyph = match ch.to_ascii_uppercase() {
+        'A' => Glyph::new(&[
+            &[(0.0, 0.0), (0.0, 4.0), (2.0, 6.0), (4.0, 4.0), (4.0, 0.0)],
+            &[(0.0, 2.0), (4.0, 2.0)],
+        ]),
+        'B' => Glyph::new(&[
+            &[
+                (0.0, 0.0),
+                (0.0, 6.0),
+                (3.0, 6.0),
+                (4.0, 5.0),
+                (4.0, 4.0),
+                (3.0, 3.0),
+                (0.0, 3.0),
+            ],
+            &[(3.0, 3.0), (4.0, 2.0), (4.0, 1.0), (3.0, 0.0), (0.0, 0.0)],
+        ]),
+        'C' => Glyph::new(&[&[(4.0, 0.0), (0.0, 0.0), (0.0, 6.0), (4.0, 6.0)]]),
+        'D' => Glyph::new(&[&[
+            (0.0, 0.0),
+            (0.0, 6.0),
+            (2.0, 6.0),
+            (4.0, 4.0),
+            (4.0, 2.0),
+            (2.0, 0.0),
+            (0.0, 0.0),
+        ]]),
+        'E' => Glyph::new(&[
+            &[(4.0, 0.0), (0.0, 0.0), (0.0, 6.0), (4.0, 6.0)],
+            &[(0.0, 3.0), (3.0, 3.0)],
+        ]),
+        'F' => Glyph::new(&[
+            &[(0.0, 0.0), (0.0, 6.0), (4.0, 6.0)],
+            &[(0.0, 3.0), (3.0, 3.0)],
+        ]),
+        'G' => Glyph::new(&[&[
+            (2.0, 2.0),
+            (4.0, 2.0),
+            (4.0, 0.0),
+            (0.0, 0.0),
+            (0.0, 6.0),
+            (4.0, 6.0),
+            (4.0, 4.0),
+        ]]),
+        'H' => Glyph::new(&[
+            &[(0.0, 0.0), (0.0, 6.0)],
+            &[(4.0, 0.0), (4.0, 6.0)],
+            &[(0.0, 3.0), (4.0, 3.0)],
+        ]),
+        'I' => Glyph::new(&[
+            &[(0.0, 0.0), (4.0, 0.0)],
+            &[(2.0, 0.0), (2.0, 6.0)],
+            &[(0.0, 6.0), (4.0, 6.0)],
+        ]),
+        'J' => Glyph::new(&[&[(0.0, 2.0), (2.0, 0.0), (4.0, 0.0), (4.0, 6.0)]]),
+        'K' => Glyph::new(&[
+            &[(0.0, 0.0), (0.0, 6.0)],
+            &[(4.0, 6.0), (0.0, 3.0), (4.0, 0.0)],
+        ]),
+        'L' => Glyph::new(&[&[(0.0, 6.0), (0.0, 0.0), (4.0, 0.0)]]),
+        'M' => Glyph::new(&[&[(0.0, 0.0), (0.0, 6.0), (2.0, 4.0), (4.0, 6.0), (4.0, 0.0)]]),
+        'N' => Glyph::new(&[&[(0.0, 0.0), (0.0, 6.0), (4.0, 0.0), (4.0, 6.0)]]),
+        'O' | '0' => Glyph::new(&[&[(0.0, 0.0), (0.0, 6.0), (4.0, 6.0), (4.0, 0.0), (0.0, 0.0)]]),
+        'P' => Glyph::new(&[&[(0.0, 0.0), (0.0, 6.0), (4.0, 6.0), (4.0, 3.0), (0.0, 3.0)]]),
+        'Q' => Glyph::new(&[
+            &[
+                (0.0, 0.0),
+                (0.0, 6.0),
+                (4.0, 6.0),
+                (4.0, 2.0),
+                (2.0, 0.0),
+                (0.0, 0.0),
+            ],
+            &[(2.0, 2.0), (4.0, 0.0)],
+        ]),
+        'R' => Glyph::new(&[
+            &[(0.0, 0.0), (0.0, 6.0), (4.0, 6.0), (4.0, 3.0), (0.0, 3.0)],
+            &[(1.0, 3.0), (4.0, 0.0)],
+        ]),
+        'S' | '5' => Glyph::new(&[&[
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 3.0),
+            (0.0, 3.0),
+            (0.0, 6.0),
+            (4.0, 6.0),
+        ]]),
+        'T' => Glyph::new(&[&[(0.0, 6.0), (4.0, 6.0)], &[(2.0, 6.0), (2.0, 0.0)]]),
+        'U' => Glyph::new(&[&[(0.0, 6.0), (0.0, 0.0), (4.0, 0.0), (4.0, 6.0)]]),
+        'V' => Glyph::new(&[&[(0.0, 6.0), (2.0, 0.0), (4.0, 6.0)]]),
+        'W' => Glyph::new(&[&[(0.0, 6.0), (0.0, 0.0), (2.0, 2.0), (4.0, 0.0), (4.0, 6.0)]]),
+        'X' => Glyph::new(&[&[(0.0, 0.0), (4.0, 6.0)], &[(0.0, 6.0), (4.0, 0.0)]]),
+        'Y' => Glyph::new(&[
+            &[(0.0, 6.0), (2.0, 4.0), (4.0, 6.0)],
+            &[(2.0, 4.0), (2.0, 0.0)],
+        ]),
+        'Z' => Glyph::new(&[&[(0.0, 6.0), (4.0, 6.0), (0.0, 0.0), (4.0, 0.0)]]),
+        '1' => Glyph::new(&[&[(2.0, 0.0), (2.0, 6.0)]]),
+        '2' => Glyph::new(&[&[
+            (0.0, 6.0),
+            (4.0, 6.0),
+            (4.0, 3.0),
+            (0.0, 3.0),
+            (0.0, 0.0),
+            (4.0, 0.0),
+        ]]),
+        '3' => Glyph::new(&[
+            &[(0.0, 0.0), (4.0, 0.0), (4.0, 6.0), (0.0, 6.0)],
+            &[(0.0, 3.0), (4.0, 3.0)],
+        ]),
+        '4' => Glyph::new(&[
+            &[(0.0, 6.0), (0.0, 3.0), (4.0, 3.0)],
+            &[(4.0, 6.0), (4.0, 0.0)],
+        ]),
+        '6' => Glyph::new(&[&[(0.0, 6.0), (0.0, 0.0), (4.0, 0.0), (4.0, 3.0), (0.0, 3.0)]]),
+        '7' => Glyph::new(&[&[(0.0, 6.0), (4.0, 6.0), (4.0, 0.0)]]),
+        '8' => Glyph::new(&[
+            &[(0.0, 0.0), (4.0, 0.0), (4.0, 6.0), (0.0, 6.0), (0.0, 0.0)],
+            &[(0.0, 3.0), (4.0, 3.0)],
+        ]),
+        '9' => Glyph::new(&[&[(4.0, 0.0), (4.0, 6.0), (0.0, 6.0), (0.0, 3.0), (4.0, 3.0)]]),
+        ' ' => Glyph::new(&[]),
+        '.' => Glyph::narrow(&[&[(0.5, 0.0), (0.5, 0.5)]], 3.0),
+        ',' => Glyph::narrow(&[&[(1.0, 0.5), (0.0, -1.0)]], 3.0),
+        ':' => Glyph::narrow(&[&[(0.5, 1.0), (0.5, 1.5)], &[(0.5, 4.0), (0.5, 4.5)]], 3.0),
+        ';' => Glyph::narrow(
+            &[&[(1.0, 4.0), (1.0, 4.5)], &[(1.0, 1.0), (0.0, -1.0)]],
+            3.0,
+        ),
+        '!' => Glyph::narrow(&[&[(0.5, 6.0), (0.5, 2.0)], &[(0.5, 0.0), (0.5, 0.5)]], 3.0),
+        '\'' => Glyph::narrow(&[&[(0.5, 6.0), (0.5, 4.5)]], 3.0),
+        '"' => Glyph::narrow(&[&[(0.5, 6.0), (0.5, 4.5)], &[(2.0, 6.0), (2.0, 4.5)]], 4.5),
+        '(' => Glyph::narrow(&[&[(2.0, 6.0), (1.0, 5.0), (1.0, 1.0), (2.0, 0.0)]], 4.0),
+        ')' => Glyph::narrow(&[&[(0.0, 6.0), (1.0, 5.0), (1.0, 1.0), (0.0, 0.0)]], 4.0),
+        '[' => Glyph::narrow(&[&[(2.0, 6.0), (0.0, 6.0), (0.0, 0.0), (2.0, 0.0)]], 4.0),
+        ']' => Glyph::narrow(&[&[(0.0, 6.0), (2.0, 6.0), (2.0, 0.0), (0.0, 0.0)]], 4.0),
+        '?' => Glyph::new(&[
+            &[
+                (0.0, 4.0),
+                (0.0, 6.0),
+                (4.0, 6.0),
+                (4.0, 3.0),
+                (2.0, 3.0),
+                (2.0, 1.5),
+            ],
+            &[(2.0, 0.0), (2.0, 0.5)],
+        ]),
+        '-' => Glyph::new(&[&[(1.0, 3.0), (3.0, 3.0)]]),
+        '+' => Glyph::new(&[&[(1.0, 3.0), (3.0, 3.0)], &[(2.0, 2.0), (2.0, 4.0)]]),
+        '=' => Glyph::new(&[&[(1.0, 2.0), (3.0, 2.0)], &[(1.0, 4.0), (3.0, 4.0)]]),
+        '*' => Glyph::new(&[
+            &[(0.0, 3.0), (4.0, 3.0)],
+            &[(1.0, 1.0), (3.0, 5.0)],
+            &[(1.0, 5.0), (3.0, 1.0)],
+        ]),
+        '/' => Glyph::new(&[&[(0.0, 0.0), (4.0, 6.0)]]),
+        '\\' => Glyph::new(&[&[(0.0, 6.0), (4.0, 0.0)]]),
+        '<' => Glyph::new(&[&[(3.0, 5.0), (1.0, 3.0), (3.0, 1.0)]]),
+        '>' => Glyph::new(&[&[(1.0, 5.0), (3.0, 3.0), (1.0, 1.0)]]),
+        '_' => Glyph::new(&[&[(0.0, -0.5), (4.0, -0.5)]]),
+        '#' => Glyph::new(&[
+            &[(1.0, 0.0), (1.0, 6.0)],
+            &[(3.0, 0.0), (3.0, 6.0)],
+            &[(0.0, 2.0), (4.0, 2.0)],
+            &[(0.0, 4.0), (4.0, 4.0)],
+        ]),
+        '%' => Glyph::new(&[
+            &[(0.0, 0.0), (4.0, 6.0)],
+            &[(0.0, 6.0), (1.0, 6.0), (1.0, 5.0), (0.0, 5.0), (0.0, 6.0)],
+            &[(3.0, 1.0), (4.0, 1.0), (4.0, 0.0), (3.0, 0.0), (3.0, 1.0)],
+        ]),
+        '°' => Glyph::narrow(
+            &[&[(0.0, 6.0), (1.0, 6.0), (1.0, 5.0), (0.0, 5.0), (0.0, 6.0)]],
+            3.0,
+        ),
+        '©' => Glyph::new(&[
+            &[
+                (1.0, 0.0),
+                (3.0, 0.0),
+                (4.0, 1.0),
+                (4.0, 5.0),
+                (3.0, 6.0),
+                (1.0, 6.0),
+                (0.0, 5.0),
+                (0.0, 1.0),
+                (1.0, 0.0),
+            ],
+            &[(3.0, 2.0), (1.5, 2.0), (1.5, 4.0), (3.0, 4.0)],
+        ]),
+        '↑' => Glyph::new(&[
+            &[(2.0, 0.0), (2.0, 6.0)],
+            &[(0.0, 4.0), (2.0, 6.0), (4.0, 4.0)],
+        ]),
+        '↓' => Glyph::new(&[
+            &[(2.0, 6.0), (2.0, 0.0)],
+            &[(0.0, 2.0), (2.0, 0.0), (4.0, 2.0)],
+        ]),
+        '←' => Glyph::new(&[
+            &[(4.0, 3.0), (0.0, 3.0)],
+            &[(2.0, 5.0), (0.0, 3.0), (2.0, 1.0)],
+        ]),
+        '→' => Glyph::new(&[
+            &[(0.0, 3.0), (4.0, 3.0)],
+            &[(2.0, 5.0), (4.0, 3.0), (2.0, 1.0)],
+        ]),
+        // The player ship, nose up, as drawn for spare lives
+        SHIP_ICON => Glyph::new(&[
+            &[(2.0, 6.0), (0.0, 0.0)],
+            &[(2.0, 6.0), (4.0, 0.0)],
+            &[(0.5, 1.5), (3.5, 1.5)],
+        ]),
+        SAUCER_ICON => Glyph::new(&[
+            &[
+                (0.0, 2.0),
+                (4.0, 2.0),
+                (3.0, 1.0),
+                (1.0, 1.0),
+                (0.0, 2.0),
+                (1.0, 3.0),
+                (3.0, 3.0),
+                (4.0, 2.0),
+            ],
+            &[(1.0, 3.0), (1.5, 4.0), (2.5, 4.0), (3.0, 3.0)],
+        ]),
+        _ => return None,
+    };
+    Some(glyph)
+}
+
+// Spacing adjustment between two glyphs, in grid units (negative pulls them together)
+pub fn kerning(left: char, right: char) -> f32 {
+    match (left.to_ascii_uppercase(), right.to_ascii_uppercase()) {
+        ('A', 'V') | ('V', 'A') | ('A', 'T') | ('T', 'A') | ('A', 'Y') | ('Y', 'A') => -1.0,
+        ('L', 'T') | ('L', 'V') | ('L', 'Y') => -1.0,
+        ('P', 'A') | ('F', 'A') => -0.5,
+        ('T', '.') | ('T', ',') | ('V', '.') | ('V', ',') | ('Y', '.') | ('Y', ',') => -1.0,
+        _ => 0.0,
+    }
+}
+
+// The glyph for `ch`, or a crossed box if the font does not have it
+pub fn glyph_or_missing(ch: char) -> Glyph {
+    glyph(ch).unwrap_or(MISSING)
+}
+
+// Draw one glyph with its baseline-left corner at (x, y) and the given cap height
+pub fn render_glyph(glyph: &Glyph, x: f32, y: f32, height: f32, color: Color) -> Vec<Vertex> {
+    let scale = height / GLYPH_HEIGHT;
+    let color = color.to_array();
+    let mut vertices = Vec::new();
+
+    for stroke in glyph.strokes {
+        for segment in stroke.windows(2) {
+            for &(gx, gy) in segment {
+                vertices.push(Vertex {
+                    position: [x + gx * scale, y + gy * scale],
+                    color,
+                });
+            }
+        }
+    }
+
+    vertices
+}
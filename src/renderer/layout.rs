@@ -0,0 +1,387 @@
+// Text layout on top of the stroke font and the 7-segment digits: measuring, alignment,
+// wrapping to a width, letter spacing and number formatting.
+//
+// A stroke label of size `s` has a cap height of 0.6 * s, and a 7-segment number of
+// size `s` is `s` tall.
+
+use super::{font, render_digit, Color, Vertex};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Align {
+    Left,   // x is the left edge
+    Center, // x is the middle
+    Right,  // x is the right edge
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Face {
+    Stroke,  // The Atari-style font
+    Segment, // 7-segment digits; other characters fall back to the stroke font
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TextStyle {
+    pub size: f32,
+    pub color: Color,
+    pub face: Face,
+    pub align: Align,
+    pub letter_spacing: f32, // Extra space between characters, as a fraction of size
+    pub line_height: f32,    // Baseline to baseline, as a multiple of size
+    pub wrap_width: Option<f32>, // Break lines at spaces to fit this width
+}
+
+impl TextStyle {
+    // Left-aligned stroke font text
+    pub fn label(size: f32, color: Color) -> Self {
+        Self {
+            size,
+            color,
+            face: Face::Stroke,
+            align: Align::Left,
+            letter_spacing: 0.0,
+            line_height: 1.0,
+            wrap_width: None,
+        }
+    }
+
+    // Left-aligned 7-segment digits
+    pub fn number(size: f32, color: Color) -> Self {
+        Self {
+            face: Face::Segment,
+            line_height: 1.5,
+            ..Self::label(size, color)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct NumberFormat {
+    pub min_digits: usize, // Zero-pad the integer part to this many digits
+    pub decimals: usize,   // Digits after the decimal point
+    pub show_plus: bool,   // Prefix positive values with '+'
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            min_digits: 1,
+            decimals: 0,
+            show_plus: false,
+        }
+    }
+}
+
+impl NumberFormat {
+    pub fn padded(min_digits: usize) -> Self {
+        Self {
+            min_digits,
+            ..Self::default()
+        }
+    }
+
+    pub fn decimal(decimals: usize) -> Self {
+        Self {
+            decimals,
+            ..Self::default()
+        }
+    }
+}
+
+// Format `value` for display, e.g. "007", "-1.5" or "+12"
+pub fn format_number(value: f64, format: &NumberFormat) -> String {
+    let value = if value.is_finite() { value } else { 0.0 };
+    let text = format!("{:.*}", format.decimals, value.abs());
+    // Rounding can turn a tiny negative value into zero, which should not show a sign
+    let negative = value < 0.0 && text.bytes().any(|b| matches!(b, b'1'..=b'9'));
+
+    let integer_digits = text.find('.').unwrap_or(text.len());
+    let padding = format.min_digits.saturating_sub(integer_digits);
+
+    let mut formatted = String::new();
+    if negative {
+        formatted.push('-');
+    } else if format.show_plus {
+        formatted.push('+');
+    }
+    formatted.push_str(&"0".repeat(padding));
+    formatted.push_str(&text);
+    formatted
+}
+
+// Horizontal space a character takes: distance to the next one, and visible width
+fn metrics(ch: char, style: &TextStyle) -> (f32, f32) {
+    match (style.face, ch) {
+        (Face::Segment, '0'..='9' | '-' | '+') => (style.size * 0.8, style.size * 0.6),
+        (Face::Segment, '.') => (style.size * 0.4, style.size * 0.1),
+        (Face::Segment, ' ') => (style.size * 0.8, 0.0),
+        _ => {
+            let scale = cap_height(style) / font::GLYPH_HEIGHT;
+            let advance = font::glyph_or_missing(ch).advance;
+            (advance * scale, (advance - font::GLYPH_SPACING) * scale)
+        }
+    }
+}
+
+// Stroke font cap height; 7-segment fallback characters match the digit height
+fn cap_height(style: &TextStyle) -> f32 {
+    match style.face {
+        Face::Stroke => style.size * 0.6,
+        Face::Segment => style.size,
+    }
+}
+
+// Offset of each character from the start of the line, and the line's width
+fn place(line: &str, style: &TextStyle) -> (Vec<(char, f32)>, f32) {
+    let mut placed = Vec::new();
+    let mut cursor = 0.0;
+    let mut width: f32 = 0.0;
+    let mut previous: Option<char> = None;
+
+    for ch in line.chars() {
+        if let Some(previous) = previous {
+            cursor += style.letter_spacing * style.size;
+            if style.face == Face::Stroke {
+                cursor += font::kerning(previous, ch) * cap_height(style) / font::GLYPH_HEIGHT;
+            }
+        }
+        let (advance, ink) = metrics(ch, style);
+        placed.push((ch, cursor));
+        width = width.max(cursor + ink);
+        cursor += advance;
+        previous = Some(ch);
+    }
+
+    (placed, width)
+}
+
+// Width of a single line of text
+pub fn line_width(line: &str, style: &TextStyle) -> f32 {
+    place(line, style).1
+}
+
+// Split `text` into lines at newlines, then at spaces to fit the style's wrap width.
+// Words wider than the wrap width are broken between characters.
+pub fn wrap(text: &str, style: &TextStyle) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in text.split('\n') {
+        let Some(max_width) = style.wrap_width else {
+            lines.push(paragraph.to_string());
+            continue;
+        };
+
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", line, word)
+            };
+            if line_width(&candidate, style) <= max_width {
+                line = candidate;
+                continue;
+            }
+
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            for ch in word.chars() {
+                line.push(ch);
+                if line.chars().count() > 1 && line_width(&line, style) > max_width {
+                    line.pop();
+                    lines.push(std::mem::replace(&mut line, ch.to_string()));
+                }
+            }
+        }
+        lines.push(line);
+    }
+
+    lines
+}
+
+// Width of the widest line and total height of `text` once wrapped
+pub fn measure(text: &str, style: &TextStyle) -> (f32, f32) {
+    let lines = wrap(text, style);
+    let width = lines
+        .iter()
+        .map(|line| line_width(line, style))
+        .fold(0.0, f32::max);
+    let height = cap_height(style) + (lines.len() - 1) as f32 * style.line_height * style.size;
+    (width, height)
+}
+
+// Draw `text` with the first line's baseline at `y`, aligned on `x`. Later lines go down.
+pub fn draw_text(text: &str, x: f32, y: f32, style: &TextStyle) -> Vec<Vertex> {
+    let mut vertices = Vec::new();
+
+    for (i, line) in wrap(text, style).iter().enumerate() {
+        let (placed, width) = place(line, style);
+        let left = match style.align {
+            Align::Left => x,
+            Align::Center => x - width * 0.5,
+            Align::Right => x - width,
+        };
+        let baseline = y - i as f32 * style.line_height * style.size;
+
+        for (ch, offset) in placed {
+            vertices.extend(draw_char(ch, left + offset, baseline, style));
+        }
+    }
+
+    vertices
+}
+
+// Format and draw a number
+pub fn draw_number(
+    value: f64,
+    format: &NumberFormat,
+    x: f32,
+    y: f32,
+    style: &TextStyle,
+) -> Vec<Vertex> {
+    draw_text(&format_number(value, format), x, y, style)
+}
+
+fn draw_char(ch: char, x: f32, y: f32, style: &TextStyle) -> Vec<Vertex> {
+    let size = style.size;
+    let color = style.color.to_array();
+    let line = |x1: f32, y1: f32, x2: f32, y2: f32| {
+        [
+            Vertex {
+                position: [x1, y1],
+                color,
+            },
+            Vertex {
+                position: [x2, y2],
+                color,
+            },
+        ]
+    };
+
+    match (style.face, ch) {
+        (Face::Segment, '0'..='9') => {
+            render_digit(ch.to_digit(10).unwrap_or(0), x, y, size, style.color)
+        }
+        (Face::Segment, '-') => line(x, y + size * 0.5, x + size * 0.6, y + size * 0.5).to_vec(),
+        (Face::Segment, '+') => {
+            let mut vertices = line(x, y + size * 0.5, x + size * 0.6, y + size * 0.5).to_vec();
+            vertices.extend(line(
+                x + size * 0.3,
+                y + size * 0.2,
+                x + size * 0.3,
+                y + size * 0.8,
+            ));
+            vertices
+        }
+        (Face::Segment, '.') => line(x, y, x + size * 0.1, y).to_vec(),
+        (Face::Segment, ' ') => Vec::new(),
+        _ => font::render_glyph(
+            &font::glyph_or_missing(ch),
+            x,
+            y,
+            cap_height(style),
+            style.color,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrapped(text: &str, width: f32) -> Vec<String> {
+        let style = TextStyle {
+            wrap_width: Some(width),
+            ..TextStyle::label(0.1, Color::WHITE)
+        };
+        wrap(text, &style)
+    }
+
+    #[test]
+    fn wrap_without_a_width_only_splits_newlines() {
+        let style = TextStyle::label(0.1, Color::WHITE);
+        assert_eq!(
+            wrap("GAME  OVER\nPRESS START", &style),
+            ["GAME  OVER", "PRESS START"]
+        );
+        assert_eq!(wrap("", &style), [""]);
+    }
+
+    #[test]
+    fn wrap_breaks_at_spaces_to_fit() {
+        let style = TextStyle::label(0.1, Color::WHITE);
+        let width = line_width("INSERT COIN", &style);
+        assert_eq!(
+            wrapped("INSERT COIN TO PLAY", width),
+            ["INSERT COIN", "TO PLAY"]
+        );
+        // Runs of spaces collapse, and blank paragraphs stay as empty lines
+        assert_eq!(
+            wrapped("  INSERT   COIN \n\nGO", width),
+            ["INSERT COIN", "", "GO"]
+        );
+        assert_eq!(wrapped("", width), [""]);
+    }
+
+    #[test]
+    fn wrap_splits_words_wider_than_the_width() {
+        let style = TextStyle::label(0.1, Color::WHITE);
+        let width = line_width("ABCD", &style);
+        let lines = wrapped("HI ABCDEFGHIJ", width);
+        assert_eq!(lines, ["HI", "ABCD", "EFGH", "IJ"]);
+        assert!(lines.iter().all(|line| line_width(line, &style) <= width));
+    }
+
+    #[test]
+    fn wrap_to_zero_width_puts_each_character_on_its_own_line() {
+        assert_eq!(wrapped("AB C", 0.0), ["A", "B", "C"]);
+    }
+
+    #[test]
+    fn measure_counts_wrapped_lines() {
+        let style = TextStyle {
+            line_height: 1.5,
+            ..TextStyle::label(0.1, Color::WHITE)
+        };
+        let (width, height) = measure("WAVE\nONE", &style);
+        assert_eq!(width, line_width("WAVE", &style));
+        assert!((height - (0.06 + 0.15)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn format_number_pads_signs_and_rounds() {
+        assert_eq!(format_number(7.0, &NumberFormat::padded(3)), "007");
+        assert_eq!(format_number(-7.0, &NumberFormat::padded(3)), "-007");
+        assert_eq!(format_number(1234.0, &NumberFormat::padded(3)), "1234");
+        assert_eq!(format_number(-1.5, &NumberFormat::decimal(1)), "-1.5");
+        assert_eq!(format_number(0.125, &NumberFormat::decimal(2)), "0.12");
+        assert_eq!(format_number(2.96, &NumberFormat::decimal(1)), "3.0");
+
+        let signed = NumberFormat {
+            show_plus: true,
+            ..NumberFormat::default()
+        };
+        assert_eq!(format_number(12.0, &signed), "+12");
+        assert_eq!(format_number(0.0, &signed), "+0");
+        assert_eq!(format_number(-3.0, &signed), "-3");
+    }
+
+    #[test]
+    fn format_number_edge_cases() {
+        // A negative value that rounds to zero has no sign
+        assert_eq!(format_number(-0.04, &NumberFormat::decimal(1)), "0.0");
+        assert_eq!(format_number(-0.0, &NumberFormat::default()), "0");
+        // Non-finite values show as zero
+        assert_eq!(format_number(f64::NAN, &NumberFormat::default()), "0");
+        assert_eq!(format_number(f64::INFINITY, &NumberFormat::padded(2)), "00");
+        // Digits are not grouped
+        assert_eq!(
+            format_number(1234567.0, &NumberFormat::default()),
+            "1234567"
+        );
+        assert_eq!(
+            format_number(-9876543.0, &NumberFormat::default()),
+            "-9876543"
+        );
+    }
+}
@@ -1,14 +1,21 @@
+mod backend;
+pub mod font;
+pub mod layout;
 mod pipeline;
+pub mod raster;
+pub mod software_window;
 mod text;
 mod vertex;
 
-pub use text::{render_digit, render_label, render_number};
+pub use backend::{Frame, HeadlessBackend, Layer, RenderBackend, RenderError, Segment};
+pub use layout::{Align, NumberFormat, TextStyle};
+pub use text::render_digit;
 pub use vertex::Vertex;
 
 use wgpu::{Device, Queue, Surface, SurfaceConfiguration};
 use winit::window::Window;
 
-use crate::game::GameState;
+use crate::profiler;
 
 // Retro 8-color palette
 #[repr(C)]
@@ -85,6 +92,10 @@ impl Color {
     pub fn to_array(&self) -> [f32; 4] {
         [self.r, self.g, self.b, self.a]
     }
+
+    pub fn from_array([r, g, b, a]: [f32; 4]) -> Self {
+        Color { r, g, b, a }
+    }
 }
 
 pub struct Renderer<'a> {
@@ -97,7 +108,8 @@ pub struct Renderer<'a> {
 }
 
 impl<'a> Renderer<'a> {
-    pub async fn new(window: &'a Window) -> Self {
+    // Fails when there is no Metal adapter; the binary then uses a SoftwareWindow
+    pub async fn new(window: &'a Window) -> Result<Self, RenderError> {
         let size = window.inner_size();
 
         let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
@@ -105,7 +117,9 @@ impl<'a> Renderer<'a> {
             ..Default::default()
         });
 
-        let surface = instance.create_surface(window).unwrap();
+        let surface = instance
+            .create_surface(window)
+            .map_err(|e| RenderError::Other(format!("could not create surface: {}", e)))?;
 
         let adapter = instance
             .request_adapter(&wgpu::RequestAdapterOptions {
@@ -114,7 +128,7 @@ impl<'a> Renderer<'a> {
                 force_fallback_adapter: false,
             })
             .await
-            .unwrap();
+            .ok_or_else(|| RenderError::Other("no compatible GPU adapter".to_string()))?;
 
         let (device, queue) = adapter
             .request_device(
@@ -126,7 +140,7 @@ impl<'a> Renderer<'a> {
                 None,
             )
             .await
-            .unwrap();
+            .map_err(|e| RenderError::Other(format!("could not open GPU device: {}", e)))?;
 
         let surface_caps = surface.get_capabilities(&adapter);
         let surface_format = surface_caps
@@ -151,14 +165,14 @@ impl<'a> Renderer<'a> {
 
         let pipeline = pipeline::RenderPipeline::new(&device, &config);
 
-        Self {
+        Ok(Self {
             surface,
             device,
             queue,
             config,
             size,
             pipeline,
-        }
+        })
     }
 
     pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
@@ -170,14 +184,23 @@ impl<'a> Renderer<'a> {
         }
     }
 
-    pub fn render(&mut self, game_state: &GameState) -> Result<(), wgpu::SurfaceError> {
-        let output = self.surface.get_current_texture()?;
+    // Draw a line list (pairs of vertices in -1..1 screen coordinates) over the clear
+    // colour. Frames come in through RenderBackend::render_frame.
+    fn draw(&mut self, vertices: &[Vertex], clear_color: Color) -> Result<(), wgpu::SurfaceError> {
+        let output = {
+            let _span = profiler::span("acquire_frame");
+            self.surface.get_current_texture()?
+        };
         let view = output
             .texture
             .create_view(&wgpu::TextureViewDescriptor::default());
 
-        // Get vertices and create buffer before render pass
-        let vertices = game_state.get_vertices();
+        let clear = wgpu::Color {
+            r: clear_color.r as f64,
+            g: clear_color.g as f64,
+            b: clear_color.b as f64,
+            a: clear_color.a as f64,
+        };
 
         let mut encoder = self
             .device
@@ -188,13 +211,15 @@ impl<'a> Renderer<'a> {
         if !vertices.is_empty() {
             use wgpu::util::DeviceExt;
 
+            let upload_span = profiler::span("vertex_upload");
             let vertex_buffer = self
                 .device
                 .create_buffer_init(&wgpu::util::BufferInitDescriptor {
                     label: Some("Vertex Buffer"),
-                    contents: bytemuck::cast_slice(&vertices),
+                    contents: bytemuck::cast_slice(vertices),
                     usage: wgpu::BufferUsages::VERTEX,
                 });
+            drop(upload_span);
 
             {
                 let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
@@ -203,12 +228,7 @@ impl<'a> Renderer<'a> {
                         view: &view,
                         resolve_target: None,
                         ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color {
-                                r: 0.0,
-                                g: 0.0,
-                                b: 0.0,
-                                a: 1.0,
-                            }),
+                            load: wgpu::LoadOp::Clear(clear),
                             store: wgpu::StoreOp::Store,
                         },
                     })],
@@ -229,12 +249,7 @@ impl<'a> Renderer<'a> {
                     view: &view,
                     resolve_target: None,
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: 0.0,
-                            g: 0.0,
-                            b: 0.0,
-                            a: 1.0,
-                        }),
+                        load: wgpu::LoadOp::Clear(clear),
                         store: wgpu::StoreOp::Store,
                     },
                 })],
@@ -244,9 +259,25 @@ impl<'a> Renderer<'a> {
             });
         }
 
-        self.queue.submit(std::iter::once(encoder.finish()));
-        output.present();
+        {
+            let _span = profiler::span("submit");
+            self.queue.submit(std::iter::once(encoder.finish()));
+        }
+        {
+            let _span = profiler::span("present");
+            output.present();
+        }
 
         Ok(())
     }
 }
+
+impl RenderBackend for Renderer<'_> {
+    fn render_frame(&mut self, frame: &Frame) -> Result<(), RenderError> {
+        Ok(self.draw(&frame.vertices(), frame.clear_color)?)
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        Renderer::resize(self, winit::dpi::PhysicalSize::new(width, height));
+    }
+}
@@ -0,0 +1,263 @@
+// Software rendering of the game's line list into an RGBA image, for when there is no
+// GPU: training agents, thumbnails, golden-image tests and the SoftwareWindow. Lines
+// are anti-aliased by how far each pixel centre is from the line, so they look the same
+// at any resolution.
+
+use super::{Color, Frame, RenderBackend, RenderError, Vertex};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+// Images are at most this many pixels a side, 1 GiB at the largest
+pub const MAX_IMAGE_SIDE: u32 = 16384;
+
+// RGBA8 pixels, row-major with the top row first
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+impl Image {
+    // Fails unless both sides are from 1 to MAX_IMAGE_SIDE
+    pub fn new(width: u32, height: u32, clear: Color) -> Result<Self, RenderError> {
+        let valid = |side: u32| (1..=MAX_IMAGE_SIDE).contains(&side);
+        if !valid(width) || !valid(height) {
+            return Err(RenderError::Other(format!(
+                "cannot make a {}x{} image; each side must be 1 to {}",
+                width, height, MAX_IMAGE_SIDE
+            )));
+        }
+
+        let clear = to_rgba8(clear.to_array());
+        Ok(Self {
+            width,
+            height,
+            pixels: clear.repeat(width as usize * height as usize),
+        })
+    }
+
+    pub fn clear(&mut self, color: Color) {
+        let color = to_rgba8(color.to_array());
+        for pixel in self.pixels.chunks_exact_mut(4) {
+            pixel.copy_from_slice(&color);
+        }
+    }
+
+    // Blend a colour over one pixel; off-image pixels are ignored
+    pub fn blend_pixel(&mut self, x: i32, y: i32, color: [f32; 4]) {
+        if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 {
+            return;
+        }
+
+        let index = (y as usize * self.width as usize + x as usize) * 4;
+        let pixel = &mut self.pixels[index..index + 4];
+        let alpha = color[3].clamp(0.0, 1.0);
+        for (channel, &value) in pixel.iter_mut().zip(&color[..3]) {
+            let old = *channel as f32 / 255.0;
+            let new = old + (value - old) * alpha;
+            *channel = (new.clamp(0.0, 1.0) * 255.0).round() as u8;
+        }
+        pixel[3] = 255;
+    }
+
+    // Anti-aliased line between two points in pixel coordinates, `width` pixels wide with
+    // round ends. Each pixel is covered by how far its centre is from the line.
+    pub fn draw_line(&mut self, from: (f32, f32), to: (f32, f32), width: f32, color: [f32; 4]) {
+        if ![from.0, from.1, to.0, to.1].iter().all(|c| c.is_finite()) {
+            return;
+        }
+        let reach = width * 0.5 + 0.5; // Pixel centres farther away than this are not covered
+
+        // Step along the longer axis (a) and cover a band across the shorter one (b)
+        let steep = (to.1 - from.1).abs() > (to.0 - from.0).abs();
+        let swap = |(x, y): (f32, f32)| if steep { (y, x) } else { (x, y) };
+        let (mut start, mut end) = (swap(from), swap(to));
+        if start.0 > end.0 {
+            std::mem::swap(&mut start, &mut end);
+        }
+        let slope = if end.0 > start.0 {
+            (end.1 - start.1) / (end.0 - start.0)
+        } else {
+            0.0
+        };
+        // Measured along b, the band is widest when the line is diagonal
+        let band = reach * (1.0 + slope * slope).sqrt() + 1.0;
+
+        let (a_size, b_size) = if steep {
+            (self.height as f32, self.width as f32)
+        } else {
+            (self.width as f32, self.height as f32)
+        };
+        let a_first = (start.0 - reach).floor().max(0.0) as i32;
+        let a_last = (end.0 + reach).ceil().min(a_size - 1.0) as i32;
+        for a in a_first..=a_last {
+            let centre = (a as f32 + 0.5).clamp(start.0, end.0);
+            let b = start.1 + (centre - start.0) * slope;
+            let b_first = (b - band).floor().max(0.0) as i32;
+            let b_last = (b + band).ceil().min(b_size - 1.0) as i32;
+            for b in b_first..=b_last {
+                let (x, y) = if steep { (b, a) } else { (a, b) };
+                let pixel_centre = (x as f32 + 0.5, y as f32 + 0.5);
+                let coverage = (reach - distance_to_segment(pixel_centre, from, to)).min(1.0);
+                if coverage > 0.0 {
+                    let [red, green, blue, alpha] = color;
+                    self.blend_pixel(x, y, [red, green, blue, alpha * coverage]);
+                }
+            }
+        }
+    }
+
+    // Draw a line list (pairs of vertices in -1..1 screen coordinates) over the image
+    pub fn draw_vertices(&mut self, vertices: &[Vertex], line_width: f32) {
+        let (width, height) = (self.width as f32, self.height as f32);
+        let to_pixel = |position: [f32; 2]| {
+            (
+                (position[0] + 1.0) * 0.5 * width,
+                (1.0 - position[1]) * 0.5 * height,
+            )
+        };
+
+        for line in vertices.chunks_exact(2) {
+            let from = to_pixel(line[0].position);
+            let to = to_pixel(line[1].position);
+            self.draw_line(from, to, line_width, line[0].color);
+        }
+    }
+
+    pub fn save_png(&self, path: &Path) -> std::io::Result<()> {
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder = png::Encoder::new(file, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    // Read a PNG of any colour type as RGBA8, e.g. a golden image to compare against
+    pub fn load_png(path: &Path) -> std::io::Result<Self> {
+        let mut decoder = png::Decoder::new(BufReader::new(File::open(path)?));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer)?;
+        buffer.truncate(info.buffer_size());
+
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer
+                .chunks_exact(3)
+                .flat_map(|p| [p[0], p[1], p[2], 255])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => buffer
+                .chunks_exact(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            png::ColorType::Grayscale => buffer.iter().flat_map(|&v| [v, v, v, 255]).collect(),
+            png::ColorType::Indexed => {
+                // Expanded to RGB or RGBA by the decoder
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "unexpected indexed PNG",
+                ));
+            }
+        };
+        Ok(Self {
+            width: info.width,
+            height: info.height,
+            pixels,
+        })
+    }
+
+    // Pixels where any channel differs from `other` by more than `tolerance`, or None if
+    // the images are different sizes
+    pub fn differing_pixels(&self, other: &Image, tolerance: u8) -> Option<usize> {
+        if self.width != other.width || self.height != other.height {
+            return None;
+        }
+        let count = self
+            .pixels
+            .chunks_exact(4)
+            .zip(other.pixels.chunks_exact(4))
+            .filter(|(a, b)| {
+                a.iter()
+                    .zip(b.iter())
+                    .any(|(x, y)| x.abs_diff(*y) > tolerance)
+            })
+            .count();
+        Some(count)
+    }
+}
+
+// Draw a line list (pairs of vertices in -1..1 screen coordinates, as produced by
+// `GameState::get_vertices`) into a new image, with one-pixel lines
+pub fn rasterize(
+    vertices: &[Vertex],
+    width: u32,
+    height: u32,
+    clear: Color,
+) -> Result<Image, RenderError> {
+    let mut image = Image::new(width, height, clear)?;
+    image.draw_vertices(vertices, 1.0);
+    Ok(image)
+}
+
+// A RenderBackend that draws on the CPU and keeps the last frame as an image
+pub struct SoftwareRenderer {
+    pub line_width: f32, // In pixels, at any resolution
+    pub image: Image,    // The last frame drawn
+}
+
+impl SoftwareRenderer {
+    pub fn new(width: u32, height: u32) -> Result<Self, RenderError> {
+        Ok(Self {
+            line_width: 1.0,
+            image: Image::new(width, height, Color::BLACK)?,
+        })
+    }
+
+    pub fn render_vertices(&mut self, vertices: &[Vertex], clear: Color) {
+        self.image.clear(clear);
+        self.image.draw_vertices(vertices, self.line_width);
+    }
+
+    // Save the last frame drawn
+    pub fn render_to_png(&self, path: &Path) -> std::io::Result<()> {
+        self.image.save_png(path)
+    }
+}
+
+impl RenderBackend for SoftwareRenderer {
+    fn render_frame(&mut self, frame: &Frame) -> Result<(), RenderError> {
+        self.render_vertices(&frame.vertices(), frame.clear_color);
+        Ok(())
+    }
+
+    // A zero size (a minimised window) or one too large keeps the old image
+    fn resize(&mut self, width: u32, height: u32) {
+        if width > 0 && height > 0 {
+            match Image::new(width, height, Color::BLACK) {
+                Ok(image) => self.image = image,
+                Err(e) => eprintln!("Software renderer: {}", e),
+            }
+        }
+    }
+}
+
+fn distance_to_segment(point: (f32, f32), from: (f32, f32), to: (f32, f32)) -> f32 {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared > 0.0 {
+        (((point.0 - from.0) * dx + (point.1 - from.1) * dy) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (x, y) = (from.0 + dx * t, from.1 + dy * t);
+    ((point.0 - x).powi(2) + (point.1 - y).powi(2)).sqrt()
+}
+
+fn to_rgba8(color: [f32; 4]) -> [u8; 4] {
+    color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
@@ -0,0 +1,68 @@
+// Shows frames drawn on the CPU by the SoftwareRenderer in a window, for when wgpu finds
+// no adapter. Slower than the GPU, but the screensaver still runs.
+
+use super::raster::SoftwareRenderer;
+use super::{Frame, RenderBackend, RenderError};
+use std::num::NonZeroU32;
+use winit::window::Window;
+
+pub struct SoftwareWindow<'a> {
+    surface: softbuffer::Surface<&'a Window, &'a Window>,
+    pub renderer: SoftwareRenderer,
+}
+
+impl<'a> SoftwareWindow<'a> {
+    pub fn new(window: &'a Window) -> Result<Self, RenderError> {
+        let surface_error =
+            |e: softbuffer::SoftBufferError| RenderError::Other(format!("software surface: {}", e));
+        let context = softbuffer::Context::new(window).map_err(surface_error)?;
+        let surface = softbuffer::Surface::new(&context, window).map_err(surface_error)?;
+
+        let size = window.inner_size();
+        let mut software_window = Self {
+            surface,
+            renderer: SoftwareRenderer::new(size.width.max(1), size.height.max(1))?,
+        };
+        software_window.resize_surface()?;
+        Ok(software_window)
+    }
+
+    // Match the surface to the image, which keeps its old size when a resize fails
+    fn resize_surface(&mut self) -> Result<(), RenderError> {
+        let image = &self.renderer.image;
+        let (Some(width), Some(height)) =
+            (NonZeroU32::new(image.width), NonZeroU32::new(image.height))
+        else {
+            return Ok(());
+        };
+        self.surface
+            .resize(width, height)
+            .map_err(|e| RenderError::Other(format!("could not resize software surface: {}", e)))
+    }
+}
+
+impl RenderBackend for SoftwareWindow<'_> {
+    fn render_frame(&mut self, frame: &Frame) -> Result<(), RenderError> {
+        self.renderer.render_frame(frame)?;
+
+        // Fails when the surface has no size yet; resizing it sets one
+        let mut buffer = self.surface.buffer_mut().map_err(|_| RenderError::Lost)?;
+        // The surface takes 0RGB pixels
+        for (out, pixel) in buffer
+            .iter_mut()
+            .zip(self.renderer.image.pixels.chunks_exact(4))
+        {
+            *out = (pixel[0] as u32) << 16 | (pixel[1] as u32) << 8 | pixel[2] as u32;
+        }
+        buffer
+            .present()
+            .map_err(|e| RenderError::Other(format!("could not present frame: {}", e)))
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        self.renderer.resize(width, height);
+        if let Err(e) = self.resize_surface() {
+            eprintln!("{}", e);
+        }
+    }
+}
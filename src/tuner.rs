@@ -0,0 +1,194 @@
+// Offline tuning of the ship AI with a genetic algorithm. Each genome is the list of
+// AiProfile parameters; fitness comes from headless games on a fixed set of seeds, so
+// a run with the same settings always produces the same result.
+
+use crate::eval::{self, EvalReport, EvalSettings};
+use crate::game::{AiPersonality, AiProfile};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+type Genome = [f32; AiProfile::PARAMETER_COUNT];
+
+// Search range for each parameter, in AiProfile::PARAMETER_NAMES order
+const PARAMETER_RANGES: [(f32, f32); AiProfile::PARAMETER_COUNT] = [
+    (0.05, 0.6), // danger_distance
+    (0.1, 0.8),  // warning_distance
+    (0.2, 3.0),  // engagement_range
+    (0.0, 1.0),  // thrust_usage
+    (0.05, 1.5), // min_approach_distance
+    (0.01, 0.2), // alignment_tolerance
+    (0.02, 1.0), // fire_probability
+    (0.1, 5.0),  // asteroid_weight
+    (0.1, 5.0),  // saucer_weight
+    (0.0, 0.8),  // energy_reserve
+    (0.0, 1.0),  // recharge_threshold
+];
+
+pub struct TunerSettings {
+    pub population: usize,
+    pub generations: usize,
+    pub elites: usize, // Best individuals copied unchanged into the next generation
+    pub mutation_rate: f32, // Chance of mutating each parameter
+    pub mutation_scale: f32, // Mutation size as a fraction of the parameter range
+    pub seeds: Vec<u64>, // Games every individual is scored on
+    pub duration: f32, // Simulated seconds per game
+    pub delta_time: f32, // Fixed simulation step
+    pub death_penalty: f32, // Points per minute one death per minute is worth
+    pub rng_seed: u64, // Seed for the genetic algorithm itself
+}
+
+impl Default for TunerSettings {
+    fn default() -> Self {
+        Self {
+            population: 24,
+            generations: 20,
+            elites: 2,
+            mutation_rate: 0.3,
+            mutation_scale: 0.15,
+            seeds: (1..=8).collect(),
+            duration: 180.0,
+            delta_time: 1.0 / 60.0,
+            death_penalty: 3000.0,
+            rng_seed: 1,
+        }
+    }
+}
+
+pub struct TunerResult {
+    pub best: AiProfile,
+    pub fitness: f32,
+    pub report: EvalReport,
+}
+
+// Higher is better: scoring quickly while staying alive
+pub fn fitness(report: &EvalReport, death_penalty: f32) -> f32 {
+    report.points_per_minute.mean - death_penalty * report.deaths_per_minute.mean
+}
+
+// Run the genetic algorithm, calling `progress(generation, best_fitness)` after each generation
+pub fn tune(settings: &TunerSettings, mut progress: impl FnMut(usize, f32)) -> TunerResult {
+    let mut rng = StdRng::seed_from_u64(settings.rng_seed);
+    let population_size = settings.population.max(2);
+
+    // Start from the hand-made personalities, filling up with random genomes
+    let mut population: Vec<Genome> = AiPersonality::ALL
+        .iter()
+        .map(|p| p.profile().parameters())
+        .take(population_size)
+        .collect();
+    while population.len() < population_size {
+        population.push(random_genome(&mut rng));
+    }
+
+    let mut scored = score_population(&population, settings);
+    for generation in 0..settings.generations {
+        progress(generation, scored[0].1);
+
+        let mut next: Vec<Genome> = scored
+            .iter()
+            .take(settings.elites.min(population_size))
+            .map(|(genome, _)| *genome)
+            .collect();
+
+        while next.len() < population_size {
+            let a = tournament(&scored, &mut rng);
+            let b = tournament(&scored, &mut rng);
+            let mut child = crossover(&a, &b, &mut rng);
+            mutate(&mut child, settings, &mut rng);
+            next.push(child);
+        }
+
+        population = next;
+        scored = score_population(&population, settings);
+    }
+
+    let (best_genome, best_fitness) = scored[0];
+    let best = AiProfile::from_parameters(best_genome);
+    let report = eval::evaluate(&settings.seeds, &eval_settings(best, settings));
+    progress(settings.generations, best_fitness);
+
+    TunerResult {
+        best,
+        fitness: best_fitness,
+        report,
+    }
+}
+
+fn eval_settings(profile: AiProfile, settings: &TunerSettings) -> EvalSettings {
+    EvalSettings {
+        profile,
+        duration: settings.duration,
+        delta_time: settings.delta_time,
+        ..EvalSettings::default()
+    }
+}
+
+// Fitness of every genome, best first. Every genome plays the same seeds.
+fn score_population(population: &[Genome], settings: &TunerSettings) -> Vec<(Genome, f32)> {
+    let mut scored: Vec<(Genome, f32)> = population
+        .iter()
+        .map(|genome| {
+            let profile = AiProfile::from_parameters(*genome);
+            let report = eval::evaluate(&settings.seeds, &eval_settings(profile, settings));
+            (*genome, fitness(&report, settings.death_penalty))
+        })
+        .collect();
+
+    // Stable sort keeps ties in population order, so results stay reproducible
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scored
+}
+
+fn random_genome(rng: &mut StdRng) -> Genome {
+    let mut genome = [0.0; AiProfile::PARAMETER_COUNT];
+    for (value, &(min, max)) in genome.iter_mut().zip(PARAMETER_RANGES.iter()) {
+        *value = rng.gen_range(min..=max);
+    }
+    repair(&mut genome);
+    genome
+}
+
+// Best of three random individuals
+fn tournament(scored: &[(Genome, f32)], rng: &mut StdRng) -> Genome {
+    (0..3)
+        .map(|_| scored[rng.gen_range(0..scored.len())])
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(genome, _)| genome)
+        .unwrap_or(scored[0].0)
+}
+
+// Each parameter is a random blend of the two parents
+fn crossover(a: &Genome, b: &Genome, rng: &mut StdRng) -> Genome {
+    let mut child = *a;
+    for (value, &other) in child.iter_mut().zip(b.iter()) {
+        let t: f32 = rng.gen_range(0.0..=1.0);
+        *value = *value * t + other * (1.0 - t);
+    }
+    child
+}
+
+fn mutate(genome: &mut Genome, settings: &TunerSettings, rng: &mut StdRng) {
+    for (value, &(min, max)) in genome.iter_mut().zip(PARAMETER_RANGES.iter()) {
+        if rng.gen_bool(settings.mutation_rate.clamp(0.0, 1.0) as f64) {
+            *value += gaussian(rng) * settings.mutation_scale * (max - min);
+        }
+    }
+    repair(genome);
+}
+
+// Keep parameters inside their ranges, and the warning zone outside the danger zone
+fn repair(genome: &mut Genome) {
+    for (value, &(min, max)) in genome.iter_mut().zip(PARAMETER_RANGES.iter()) {
+        *value = value.clamp(min, max);
+    }
+    if genome[1] < genome[0] {
+        genome.swap(0, 1);
+    }
+}
+
+// Standard normal sample (Box-Muller)
+fn gaussian(rng: &mut StdRng) -> f32 {
+    let u1: f32 = rng.gen_range(f32::EPSILON..1.0);
+    let u2: f32 = rng.gen_range(0.0..1.0);
+    (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+}
@@ -0,0 +1,53 @@
+// Golden-image test: a seeded game played for ten seconds, drawn by the software
+// renderer, must match the PNG saved in tests/golden. After a deliberate change to how
+// the game looks, rewrite the image with
+//
+//   UPDATE_GOLDEN=1 cargo test --test golden_image
+
+use asteroids_screensaver::game::GameState;
+use asteroids_screensaver::renderer::raster::{Image, SoftwareRenderer};
+use asteroids_screensaver::AsteroidsScreensaver;
+use std::path::Path;
+
+const GOLDEN: &str = "tests/golden/seed_7_320x200.png";
+const TOLERANCE: u8 = 8; // Per channel, for rounding differences between platforms
+const MAX_DIFFERING_PIXELS: usize = 20;
+
+fn snapshot() -> Image {
+    let renderer = SoftwareRenderer::new(320, 200).unwrap();
+    let mut screensaver = AsteroidsScreensaver::with_backend(renderer);
+    screensaver.game_state = GameState::with_seed(7);
+    screensaver.attract.enabled = false; // Straight into the game
+    screensaver.resize(320, 200);
+
+    for _ in 0..600 {
+        screensaver.update(1.0 / 60.0);
+    }
+    screensaver.render().unwrap();
+    screensaver.renderer.image
+}
+
+#[test]
+fn seeded_snapshot_matches_golden_image() {
+    let image = snapshot();
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(GOLDEN);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        image.save_png(&path).unwrap();
+    }
+
+    let golden = Image::load_png(&path).unwrap();
+    let differing = image
+        .differing_pixels(&golden, TOLERANCE)
+        .expect("snapshot and golden image differ in size");
+    assert!(
+        differing <= MAX_DIFFERING_PIXELS,
+        "{} pixels differ from {}",
+        differing,
+        GOLDEN
+    );
+}
+
+#[test]
+fn snapshots_are_deterministic() {
+    assert_eq!(snapshot().differing_pixels(&snapshot(), 0), Some(0));
+}